@@ -8,6 +8,15 @@
     // An array of all contexts to resume on the page
     const audioContextList = [];
 
+    // Read by the game: one-shot sounds are dropped until audio is actually running
+    self.feverDreamAudioUnlocked = false;
+
+    function updateUnlocked() {
+        self.feverDreamAudioUnlocked =
+            audioContextList.length > 0 &&
+            audioContextList.every((context) => context.state === "running");
+    }
+
     // An array of various user interaction events we should listen for
     const userInputEventNames = [
         "click",
@@ -28,6 +37,8 @@
         construct(target, args) {
             const result = new target(...args);
             audioContextList.push(result);
+            result.addEventListener("statechange", updateUnlocked);
+            updateUnlocked();
             return result;
         },
     });
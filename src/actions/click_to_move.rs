@@ -0,0 +1,197 @@
+//! Click / tap-to-move: pick a spot in the arena and the player steers there.
+//!
+//! This only writes a direction into [`Actions::player_movement`], so mood
+//! physics (gravity, the Sideways rotation) still act on top of it in `move_player`.
+
+use bevy::prelude::*;
+use bevy::ui::UiSystems;
+use bevy::window::PrimaryWindow;
+
+use crate::actions::touch::{JoystickTouch, TouchInputSystems};
+use crate::actions::{Actions, ActionsSystems};
+use crate::collision::{Collider, Layers};
+use crate::level::NAV_CLEARANCE;
+use crate::pathfinding::NavGrid;
+use crate::player::{PLAYER_HALF_EXTENTS, Player, WorldBounds};
+use crate::{ControlScheme, GameConfig, GameState, PauseState};
+
+pub struct ClickToMovePlugin;
+
+/// Grid resolution used for planning
+const NAV_CELL: f32 = 12.0;
+/// Distance at which a waypoint counts as reached
+const WAYPOINT_REACHED: f32 = 6.0;
+/// Re-plan this often, since moods push the player off the path
+const REPLAN_SECS: f32 = 0.25;
+
+#[derive(Resource)]
+struct ClickTarget {
+    goal: Option<Vec2>,
+    path: Vec<Vec2>,
+    replan: Timer,
+}
+
+impl Default for ClickTarget {
+    fn default() -> Self {
+        Self {
+            goal: None,
+            path: Vec::new(),
+            replan: Timer::from_seconds(REPLAN_SECS, TimerMode::Repeating),
+        }
+    }
+}
+
+impl Plugin for ClickToMovePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ClickTarget>()
+            .add_systems(
+                PreUpdate,
+                (pick_click_target, steer_to_target)
+                    .chain()
+                    .in_set(ActionsSystems)
+                    .after(TouchInputSystems)
+                    .after(UiSystems::Focus)
+                    .run_if(
+                        in_state(GameState::Playing)
+                            .and(in_state(PauseState::Running))
+                            .and(click_to_move_enabled),
+                    ),
+            )
+            .add_systems(
+                Update,
+                draw_click_path.run_if(in_state(GameState::Playing).and(click_to_move_enabled)),
+            )
+            .add_systems(OnEnter(GameState::Countdown), clear_click_target);
+    }
+}
+
+fn click_to_move_enabled(config: Res<GameConfig>) -> bool {
+    config.control_scheme == ControlScheme::ClickToMove
+}
+
+fn clear_click_target(mut target: ResMut<ClickTarget>) {
+    *target = ClickTarget::default();
+}
+
+fn pick_click_target(
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    joystick: Res<JoystickTouch>,
+    mut target: ResMut<ClickTarget>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_camera: Query<(&Camera, &GlobalTransform)>,
+    q_ui: Query<&Interaction>,
+) {
+    // Presses that land on a button belong to the UI
+    if q_ui.iter().any(|i| *i != Interaction::None) {
+        return;
+    }
+
+    let screen_pos = if mouse.just_pressed(MouseButton::Left) {
+        q_window.single().ok().and_then(|w| w.cursor_position())
+    } else {
+        touches
+            .iter_just_pressed()
+            .find(|t| joystick.0 != Some(t.id()))
+            .map(|t| t.position())
+    };
+    let Some(screen_pos) = screen_pos else {
+        return;
+    };
+
+    let Ok((camera, cam_transform)) = q_camera.single() else {
+        return;
+    };
+    let Ok(world) = camera.viewport_to_world_2d(cam_transform, screen_pos) else {
+        return;
+    };
+
+    // An empty path makes the next steer plan right away
+    target.goal = Some(world);
+    target.path.clear();
+}
+
+fn steer_to_target(
+    time: Res<Time>,
+    mut actions: ResMut<Actions>,
+    mut target: ResMut<ClickTarget>,
+    q_player: Query<&Transform, With<Player>>,
+    q_bounds: Query<&Transform, With<WorldBounds>>,
+    q_walls: Query<(&Transform, &Collider), Without<Player>>,
+) {
+    // Direct input (keys, stick, joystick) always wins and cancels the walk
+    if actions.player_movement.is_some() {
+        target.goal = None;
+        target.path.clear();
+        return;
+    }
+
+    let Some(goal) = target.goal else {
+        return;
+    };
+    let Ok(player) = q_player.single() else {
+        return;
+    };
+    let pos = player.translation.truncate();
+
+    let replan_due = target.replan.tick(time.delta()).just_finished();
+    if target.path.is_empty() || replan_due {
+        let Ok(bounds) = q_bounds.single() else {
+            return;
+        };
+        let walls: Vec<Rect> = q_walls
+            .iter()
+            .filter(|(_, c)| c.layers.intersects(Layers::WALL))
+            .map(|(t, c)| {
+                Rect::from_center_half_size(t.translation.truncate(), c.shape.half_extents())
+            })
+            .collect();
+
+        let grid = NavGrid::build(
+            bounds.translation.truncate(),
+            PLAYER_HALF_EXTENTS,
+            &walls,
+            PLAYER_HALF_EXTENTS + NAV_CLEARANCE,
+            NAV_CELL,
+        );
+        target.path = grid.find_path(pos, goal).unwrap_or_default();
+
+        if target.path.is_empty() {
+            // Unreachable spot
+            target.goal = None;
+            return;
+        }
+    }
+
+    while target
+        .path
+        .first()
+        .is_some_and(|w| w.distance(pos) <= WAYPOINT_REACHED)
+    {
+        target.path.remove(0);
+    }
+
+    match target.path.first() {
+        Some(next) => actions.player_movement = Some((*next - pos).normalize_or_zero()),
+        None => target.goal = None,
+    }
+}
+
+fn draw_click_path(
+    target: Res<ClickTarget>,
+    q_player: Query<&Transform, With<Player>>,
+    mut gizmos: Gizmos,
+) {
+    let Some(goal_marker) = target.path.last().copied().or(target.goal) else {
+        return;
+    };
+    let color = Color::srgba(0.20, 0.90, 0.95, 0.7);
+
+    if let Ok(player) = q_player.single() {
+        let points =
+            std::iter::once(player.translation.truncate()).chain(target.path.iter().copied());
+        gizmos.linestrip_2d(points, color.with_alpha(0.35));
+    }
+    gizmos.circle_2d(Isometry2d::from_translation(goal_marker), 10.0, color);
+    gizmos.circle_2d(Isometry2d::from_translation(goal_marker), 4.0, color);
+}
@@ -1,32 +1,437 @@
-use bevy::prelude::{ButtonInput, KeyCode};
+use std::collections::BTreeMap;
 
-#[derive(Debug, Clone, Copy)]
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::mood::MOOD_SLOTS;
+
+/// Logical inputs. Physical keys / buttons are mapped onto these by [`InputBindings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum GameControl {
     Up,
     Down,
     Left,
     Right,
+    /// Switch straight to the mood at this index of the mood file
+    Mood(u8),
+    MoodNext,
+    MoodPrev,
+    Confirm,
+    Skip,
+    Replay,
+    BackToMenu,
+    Pause,
+    Cancel,
+    Quit,
+    // Level editor (dev builds). Always part of the keymap so one settings file fits every build.
+    EditorWallTool,
+    EditorMemoryTool,
+    EditorStartTool,
+    EditorSnap,
+    EditorMemoryDifficulty,
+    EditorWider,
+    EditorNarrower,
+    EditorTaller,
+    EditorShorter,
+    EditorSave,
+    EditorTestPlay,
 }
 
 impl GameControl {
-    pub fn pressed(self, keyboard_input: &ButtonInput<KeyCode>) -> bool {
+    pub const ALL: [GameControl; 24 + MOOD_SLOTS as usize] = [
+        GameControl::Up,
+        GameControl::Down,
+        GameControl::Left,
+        GameControl::Right,
+        GameControl::Mood(0),
+        GameControl::Mood(1),
+        GameControl::Mood(2),
+        GameControl::Mood(3),
+        GameControl::Mood(4),
+        GameControl::Mood(5),
+        GameControl::Mood(6),
+        GameControl::Mood(7),
+        GameControl::Mood(8),
+        GameControl::MoodNext,
+        GameControl::MoodPrev,
+        GameControl::Confirm,
+        GameControl::Skip,
+        GameControl::Replay,
+        GameControl::BackToMenu,
+        GameControl::Pause,
+        GameControl::Cancel,
+        GameControl::Quit,
+        GameControl::EditorWallTool,
+        GameControl::EditorMemoryTool,
+        GameControl::EditorStartTool,
+        GameControl::EditorSnap,
+        GameControl::EditorMemoryDifficulty,
+        GameControl::EditorWider,
+        GameControl::EditorNarrower,
+        GameControl::EditorTaller,
+        GameControl::EditorShorter,
+        GameControl::EditorSave,
+        GameControl::EditorTestPlay,
+    ];
+
+    /// Editor keys only matter in the editor, so they may share keys with the game
+    pub fn is_editor(self) -> bool {
+        matches!(
+            self,
+            GameControl::EditorWallTool
+                | GameControl::EditorMemoryTool
+                | GameControl::EditorStartTool
+                | GameControl::EditorSnap
+                | GameControl::EditorMemoryDifficulty
+                | GameControl::EditorWider
+                | GameControl::EditorNarrower
+                | GameControl::EditorTaller
+                | GameControl::EditorShorter
+                | GameControl::EditorSave
+                | GameControl::EditorTestPlay
+        )
+    }
+
+    /// Mood slots only know their number; menus put the mood's name on them
+    pub fn label(self) -> String {
+        let label = match self {
+            GameControl::Up => "Move up",
+            GameControl::Down => "Move down",
+            GameControl::Left => "Move left",
+            GameControl::Right => "Move right",
+            GameControl::Mood(slot) => return format!("Mood {}", slot + 1),
+            GameControl::MoodNext => "Next mood",
+            GameControl::MoodPrev => "Previous mood",
+            GameControl::Confirm => "Confirm / Start",
+            GameControl::Skip => "Skip countdown",
+            GameControl::Replay => "Play again",
+            GameControl::BackToMenu => "Back to menu",
+            GameControl::Pause => "Pause",
+            GameControl::Cancel => "Cancel",
+            GameControl::Quit => "Quit",
+            GameControl::EditorWallTool => "Wall tool",
+            GameControl::EditorMemoryTool => "Memory tool",
+            GameControl::EditorStartTool => "Start tool",
+            GameControl::EditorSnap => "Grid snap",
+            GameControl::EditorMemoryDifficulty => "Memory difficulty",
+            GameControl::EditorWider => "Arena wider",
+            GameControl::EditorNarrower => "Arena narrower",
+            GameControl::EditorTaller => "Arena taller",
+            GameControl::EditorShorter => "Arena shorter",
+            GameControl::EditorSave => "Save level",
+            GameControl::EditorTestPlay => "Test play",
+        };
+        label.to_string()
+    }
+
+    fn default_bindings(self) -> Vec<InputBinding> {
+        use GamepadButton as Pad;
+        use InputBinding::{Gamepad, Key};
+
         match self {
-            GameControl::Up => {
-                keyboard_input.pressed(KeyCode::KeyW) || keyboard_input.pressed(KeyCode::ArrowUp)
+            GameControl::Up => vec![
+                Key(KeyCode::KeyW),
+                Key(KeyCode::ArrowUp),
+                Gamepad(Pad::DPadUp),
+            ],
+            GameControl::Down => vec![
+                Key(KeyCode::KeyS),
+                Key(KeyCode::ArrowDown),
+                Gamepad(Pad::DPadDown),
+            ],
+            GameControl::Left => vec![
+                Key(KeyCode::KeyA),
+                Key(KeyCode::ArrowLeft),
+                Gamepad(Pad::DPadLeft),
+            ],
+            GameControl::Right => vec![
+                Key(KeyCode::KeyD),
+                Key(KeyCode::ArrowRight),
+                Gamepad(Pad::DPadRight),
+            ],
+            GameControl::Mood(slot) => {
+                const DIGITS: [KeyCode; MOOD_SLOTS as usize] = [
+                    KeyCode::Digit1,
+                    KeyCode::Digit2,
+                    KeyCode::Digit3,
+                    KeyCode::Digit4,
+                    KeyCode::Digit5,
+                    KeyCode::Digit6,
+                    KeyCode::Digit7,
+                    KeyCode::Digit8,
+                    KeyCode::Digit9,
+                ];
+                let mut keys: Vec<InputBinding> = DIGITS
+                    .get(usize::from(slot))
+                    .map(|&k| Key(k))
+                    .into_iter()
+                    .collect();
+                // Face buttons for the first three
+                keys.extend(
+                    [Pad::West, Pad::North, Pad::East]
+                        .get(usize::from(slot))
+                        .map(|&b| Gamepad(b)),
+                );
+                keys
             }
-            GameControl::Down => {
-                keyboard_input.pressed(KeyCode::KeyS) || keyboard_input.pressed(KeyCode::ArrowDown)
+            GameControl::MoodNext => vec![Gamepad(Pad::RightTrigger)],
+            GameControl::MoodPrev => vec![Gamepad(Pad::LeftTrigger)],
+            GameControl::Confirm => vec![
+                Key(KeyCode::Enter),
+                Gamepad(Pad::South),
+                Gamepad(Pad::Start),
+            ],
+            GameControl::Skip => vec![Key(KeyCode::Space)],
+            GameControl::Replay => vec![Key(KeyCode::KeyR)],
+            GameControl::BackToMenu => vec![Key(KeyCode::KeyM), Gamepad(Pad::East)],
+            GameControl::Pause => vec![Key(KeyCode::KeyP), Gamepad(Pad::Select)],
+            GameControl::Cancel => vec![Key(KeyCode::Escape)],
+            GameControl::Quit => vec![Key(KeyCode::KeyQ)],
+            GameControl::EditorWallTool => vec![Key(KeyCode::Digit1)],
+            GameControl::EditorMemoryTool => vec![Key(KeyCode::Digit2)],
+            GameControl::EditorStartTool => vec![Key(KeyCode::Digit3)],
+            GameControl::EditorSnap => vec![Key(KeyCode::KeyG)],
+            GameControl::EditorMemoryDifficulty => vec![Key(KeyCode::KeyD)],
+            GameControl::EditorWider => vec![Key(KeyCode::ArrowRight)],
+            GameControl::EditorNarrower => vec![Key(KeyCode::ArrowLeft)],
+            GameControl::EditorTaller => vec![Key(KeyCode::ArrowUp)],
+            GameControl::EditorShorter => vec![Key(KeyCode::ArrowDown)],
+            GameControl::EditorSave => vec![Key(KeyCode::KeyS)],
+            GameControl::EditorTestPlay => vec![Key(KeyCode::KeyT)],
+        }
+    }
+}
+
+/// One physical input that can trigger a [`GameControl`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InputBinding {
+    Key(KeyCode),
+    Gamepad(GamepadButton),
+}
+
+impl InputBinding {
+    pub fn label(self) -> String {
+        match self {
+            InputBinding::Key(key) => {
+                let raw = format!("{key:?}");
+                let short = raw
+                    .strip_prefix("Key")
+                    .or_else(|| raw.strip_prefix("Digit"))
+                    .unwrap_or(&raw);
+                match short {
+                    "ArrowUp" => "↑".to_string(),
+                    "ArrowDown" => "↓".to_string(),
+                    "ArrowLeft" => "←".to_string(),
+                    "ArrowRight" => "→".to_string(),
+                    other => other.to_string(),
+                }
             }
-            GameControl::Left => {
-                keyboard_input.pressed(KeyCode::KeyA) || keyboard_input.pressed(KeyCode::ArrowLeft)
+            InputBinding::Gamepad(button) => format!("Pad {button:?}"),
+        }
+    }
+
+    fn pressed(self, keys: &ButtonInput<KeyCode>, gamepads: &Query<&Gamepad>) -> bool {
+        match self {
+            InputBinding::Key(key) => keys.pressed(key),
+            InputBinding::Gamepad(button) => gamepads.iter().any(|g| g.pressed(button)),
+        }
+    }
+
+    fn just_pressed(self, keys: &ButtonInput<KeyCode>, gamepads: &Query<&Gamepad>) -> bool {
+        match self {
+            InputBinding::Key(key) => keys.just_pressed(key),
+            InputBinding::Gamepad(button) => gamepads.iter().any(|g| g.just_pressed(button)),
+        }
+    }
+}
+
+/// The keymap: every control and the inputs bound to it
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputBindings(pub BTreeMap<GameControl, Vec<InputBinding>>);
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        Self(
+            GameControl::ALL
+                .into_iter()
+                .map(|c| (c, c.default_bindings()))
+                .collect(),
+        )
+    }
+}
+
+impl InputBindings {
+    pub fn get(&self, control: GameControl) -> &[InputBinding] {
+        self.0.get(&control).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Controls missing from an older settings file fall back to their defaults
+    pub fn fill_missing(&mut self) {
+        for control in GameControl::ALL {
+            self.0
+                .entry(control)
+                .or_insert_with(|| control.default_bindings());
+        }
+    }
+
+    /// Make `binding` the control's primary binding of its kind (keyboard or gamepad),
+    /// replacing the old primary; the control's other keys stay. Controls on the same side
+    /// of the game / editor split that already used `binding` get the old primary instead
+    /// (or lose it if there was none); returns those controls.
+    pub fn rebind(&mut self, control: GameControl, binding: InputBinding) -> Vec<GameControl> {
+        let same_kind = |b: &InputBinding| {
+            matches!(
+                (b, binding),
+                (InputBinding::Key(_), InputBinding::Key(_))
+                    | (InputBinding::Gamepad(_), InputBinding::Gamepad(_))
+            )
+        };
+        let list = self.0.entry(control).or_default();
+        let primary = list.iter().position(same_kind);
+        let previous = primary.map(|i| list[i]);
+        match (primary, list.iter().position(|&b| b == binding)) {
+            // Already one of this control's keys: it just trades places with the primary
+            (Some(primary), Some(own)) => {
+                list.swap(primary, own);
+                return Vec::new();
+            }
+            (Some(primary), None) => list[primary] = binding,
+            (None, _) => list.insert(0, binding),
+        }
+
+        let mut swapped = Vec::new();
+        for (&other, list) in &mut self.0 {
+            if other == control || other.is_editor() != control.is_editor() {
+                continue;
             }
-            GameControl::Right => {
-                keyboard_input.pressed(KeyCode::KeyD) || keyboard_input.pressed(KeyCode::ArrowRight)
+            let Some(i) = list.iter().position(|&b| b == binding) else {
+                continue;
+            };
+            match previous {
+                Some(previous) if !list.contains(&previous) => list[i] = previous,
+                _ => {
+                    list.remove(i);
+                }
             }
+            swapped.push(other);
+        }
+        swapped
+    }
+
+    /// Short hint for HUD/help text: the first keyboard binding, else the first of any kind
+    pub fn primary_label(&self, control: GameControl) -> String {
+        let list = self.get(control);
+        list.iter()
+            .find(|b| matches!(b, InputBinding::Key(_)))
+            .or(list.first())
+            .map(|b| b.label())
+            .unwrap_or_else(|| "—".to_string())
+    }
+
+    pub fn describe(&self, control: GameControl) -> String {
+        let labels: Vec<String> = self.get(control).iter().map(|b| b.label()).collect();
+        if labels.is_empty() {
+            "—".to_string()
+        } else {
+            labels.join(" / ")
         }
     }
 }
 
-pub fn get_movement(control: GameControl, input: &ButtonInput<KeyCode>) -> f32 {
-    if control.pressed(input) { 1.0 } else { 0.0 }
+/// Keyboard + gamepads read through the current [`InputBindings`]
+#[derive(SystemParam)]
+pub struct ControlInput<'w, 's> {
+    bindings: Res<'w, InputBindings>,
+    keys: Res<'w, ButtonInput<KeyCode>>,
+    gamepads: Query<'w, 's, &'static Gamepad>,
+}
+
+impl ControlInput<'_, '_> {
+    pub fn pressed(&self, control: GameControl) -> bool {
+        self.bindings
+            .get(control)
+            .iter()
+            .any(|b| b.pressed(&self.keys, &self.gamepads))
+    }
+
+    pub fn just_pressed(&self, control: GameControl) -> bool {
+        self.bindings
+            .get(control)
+            .iter()
+            .any(|b| b.just_pressed(&self.keys, &self.gamepads))
+    }
+
+    pub fn axis(&self, positive: GameControl, negative: GameControl) -> f32 {
+        let value = |c| if self.pressed(c) { 1.0 } else { 0.0 };
+        value(positive) - value(negative)
+    }
+
+    pub fn gamepads(&self) -> impl Iterator<Item = &Gamepad> {
+        self.gamepads.iter()
+    }
+}
+
+/// Radial deadzone: anything inside `deadzone` is dropped, the rest is rescaled to 0..1
+pub fn apply_deadzone(stick: Vec2, deadzone: f32) -> Vec2 {
+    let len = stick.length();
+    if len <= deadzone {
+        return Vec2::ZERO;
+    }
+
+    let scaled = ((len - deadzone) / (1.0 - deadzone).max(f32::EPSILON)).min(1.0);
+    stick / len * scaled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rebinding_a_used_key_swaps_it() {
+        let mut bindings = InputBindings::default();
+        let q = InputBinding::Key(KeyCode::KeyQ);
+        assert_eq!(
+            bindings.rebind(GameControl::Replay, q),
+            vec![GameControl::Quit]
+        );
+        assert_eq!(bindings.get(GameControl::Replay), [q]);
+        assert_eq!(
+            bindings.get(GameControl::Quit),
+            [InputBinding::Key(KeyCode::KeyR)]
+        );
+
+        // Nothing to hand back: the other control just loses the key
+        bindings.0.insert(GameControl::Skip, Vec::new());
+        assert_eq!(
+            bindings.rebind(GameControl::Skip, q),
+            vec![GameControl::Replay]
+        );
+        assert!(bindings.get(GameControl::Replay).is_empty());
+
+        // The editor has keys of its own
+        let one = InputBinding::Key(KeyCode::Digit1);
+        assert_eq!(
+            bindings.rebind(GameControl::EditorSave, one),
+            vec![GameControl::EditorWallTool]
+        );
+        assert_eq!(bindings.get(GameControl::Mood(0))[0], one);
+
+        // Only the primary key is replaced: the arrows survive a rebind of WASD
+        let (i, w, up) = (
+            InputBinding::Key(KeyCode::KeyI),
+            InputBinding::Key(KeyCode::KeyW),
+            InputBinding::Key(KeyCode::ArrowUp),
+        );
+        assert!(bindings.rebind(GameControl::Up, i).is_empty());
+        assert_eq!(
+            bindings.get(GameControl::Up),
+            [i, up, InputBinding::Gamepad(GamepadButton::DPadUp)]
+        );
+
+        // Picking a key the control already has only reorders them
+        bindings.rebind(GameControl::Up, up);
+        assert_eq!(bindings.get(GameControl::Up)[..2], [up, i]);
+        assert!(!bindings.get(GameControl::Up).contains(&w));
+    }
 }
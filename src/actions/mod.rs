@@ -1,28 +1,202 @@
+use bevy::input::InputSystems;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
+use crate::mood::{MOOD_SLOTS, Mood};
+use crate::storage;
+
+mod click_to_move;
 mod game_control;
-use game_control::GameControl;
-use game_control::get_movement;
+mod touch;
+use click_to_move::ClickToMovePlugin;
+use game_control::{ControlInput, apply_deadzone};
+use touch::TouchControlsPlugin;
+
+pub use game_control::{GameControl, InputBinding, InputBindings};
 
 pub struct ActionsPlugin;
 
-#[derive(Resource, Default)]
+/// Everything the game wants from the player this frame.
+///
+/// Only this resource is read by gameplay / menu systems, so any source
+/// (keyboard, gamepad, touch, a replay, a bot) drives the game by writing here.
+/// The `bool` intents are one-frame pulses.
+#[derive(Resource, Default, Debug, Clone)]
 pub struct Actions {
     pub player_movement: Option<Vec2>,
+    pub mood: Option<MoodIntent>,
+    pub confirm: bool,
+    pub skip: bool,
+    pub replay: bool,
+    pub cancel: bool,
+    pub pause: bool,
+    pub back_to_menu: bool,
+    pub quit: bool,
+    #[cfg(feature = "dev")]
+    pub editor: Option<EditorIntent>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoodIntent {
+    Select(Mood),
+    Next,
+    Prev,
 }
 
+/// Level editor commands
+#[cfg(feature = "dev")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EditorIntent {
+    WallTool,
+    MemoryTool,
+    StartTool,
+    ToggleSnap,
+    /// Next difficulty for new memories
+    CycleDifficulty,
+    /// Grow (+1) or shrink (-1) the arena by a grid step on each axis
+    Resize(Vec2),
+    Save,
+    TestPlay,
+}
+
+/// Systems that fill [`Actions`]; they run in `PreUpdate` so every `Update` system sees this frame's input.
+/// Extra input sources should run `.in_set(ActionsSystems).after(set_actions)`.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ActionsSystems;
+
+/// Tunables for controller input
+#[derive(Resource, Debug, Clone)]
+pub struct GamepadConfig {
+    /// Radial left-stick deadzone (0..1)
+    pub deadzone: f32,
+}
+
+impl Default for GamepadConfig {
+    fn default() -> Self {
+        Self { deadzone: 0.2 }
+    }
+}
+
+/// What ends up in the `controls` settings file
+#[derive(Serialize, Deserialize)]
+struct ControlsSettings {
+    deadzone: f32,
+    bindings: InputBindings,
+}
+
+const CONTROLS_KEY: &str = "controls";
+
 impl Plugin for ActionsPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<Actions>()
-            .add_systems(Update, set_movement_actions);
+        let (bindings, gamepad_config) = match storage::load::<ControlsSettings>(CONTROLS_KEY) {
+            Some(mut saved) => {
+                saved.bindings.fill_missing();
+                (
+                    saved.bindings,
+                    GamepadConfig {
+                        deadzone: saved.deadzone.clamp(0.0, 0.95),
+                    },
+                )
+            }
+            None => (InputBindings::default(), GamepadConfig::default()),
+        };
+
+        app.add_plugins((TouchControlsPlugin, ClickToMovePlugin))
+            .init_resource::<Actions>()
+            .insert_resource(bindings)
+            .insert_resource(gamepad_config)
+            .add_systems(
+                PreUpdate,
+                set_actions.in_set(ActionsSystems).after(InputSystems),
+            );
+    }
+}
+
+/// Persist the current keymap + deadzone
+pub fn save_controls(bindings: &InputBindings, gamepad_config: &GamepadConfig) {
+    storage::save(
+        CONTROLS_KEY,
+        &ControlsSettings {
+            deadzone: gamepad_config.deadzone,
+            bindings: bindings.clone(),
+        },
+    );
+}
+
+pub fn set_actions(
+    mut actions: ResMut<Actions>,
+    input: ControlInput,
+    gamepad_config: Res<GamepadConfig>,
+) {
+    actions.player_movement = movement(&input, &gamepad_config);
+
+    // Slots past the end of the mood file are ignored by `mood_input`
+    let slot = (0..MOOD_SLOTS).find(|&slot| input.just_pressed(GameControl::Mood(slot)));
+    actions.mood = if let Some(slot) = slot {
+        Some(MoodIntent::Select(Mood(usize::from(slot))))
+    } else if input.just_pressed(GameControl::MoodNext) {
+        Some(MoodIntent::Next)
+    } else if input.just_pressed(GameControl::MoodPrev) {
+        Some(MoodIntent::Prev)
+    } else {
+        None
+    };
+
+    actions.confirm = input.just_pressed(GameControl::Confirm);
+    actions.skip = input.just_pressed(GameControl::Skip);
+    actions.replay = input.just_pressed(GameControl::Replay);
+    actions.cancel = input.just_pressed(GameControl::Cancel);
+    actions.pause = input.just_pressed(GameControl::Pause);
+    actions.back_to_menu = input.just_pressed(GameControl::BackToMenu);
+    actions.quit = input.just_pressed(GameControl::Quit);
+    #[cfg(feature = "dev")]
+    {
+        actions.editor = editor_intent(&input);
     }
 }
 
-pub fn set_movement_actions(mut actions: ResMut<Actions>, input: Res<ButtonInput<KeyCode>>) {
-    let horizontal =
-        get_movement(GameControl::Right, &input) - get_movement(GameControl::Left, &input);
-    let vertical = get_movement(GameControl::Up, &input) - get_movement(GameControl::Down, &input);
+#[cfg(feature = "dev")]
+fn editor_intent(input: &ControlInput) -> Option<EditorIntent> {
+    let pulse = |c| if input.just_pressed(c) { 1.0 } else { 0.0 };
+    let resize = Vec2::new(
+        pulse(GameControl::EditorWider) - pulse(GameControl::EditorNarrower),
+        pulse(GameControl::EditorTaller) - pulse(GameControl::EditorShorter),
+    );
+    if resize != Vec2::ZERO {
+        return Some(EditorIntent::Resize(resize));
+    }
+
+    [
+        (GameControl::EditorWallTool, EditorIntent::WallTool),
+        (GameControl::EditorMemoryTool, EditorIntent::MemoryTool),
+        (GameControl::EditorStartTool, EditorIntent::StartTool),
+        (GameControl::EditorSnap, EditorIntent::ToggleSnap),
+        (
+            GameControl::EditorMemoryDifficulty,
+            EditorIntent::CycleDifficulty,
+        ),
+        (GameControl::EditorSave, EditorIntent::Save),
+        (GameControl::EditorTestPlay, EditorIntent::TestPlay),
+    ]
+    .into_iter()
+    .find_map(|(control, intent)| input.just_pressed(control).then_some(intent))
+}
+
+fn movement(input: &ControlInput, gamepad_config: &GamepadConfig) -> Option<Vec2> {
+    let horizontal = input.axis(GameControl::Right, GameControl::Left);
+    let vertical = input.axis(GameControl::Up, GameControl::Down);
+
+    let digital = Vec2::new(horizontal, vertical);
+    if digital != Vec2::ZERO {
+        return Some(digital.normalize_or_zero());
+    }
+
+    // Analog stick keeps its magnitude so partial tilt = slower movement
+    let stick = input
+        .gamepads()
+        .map(|g| apply_deadzone(g.left_stick(), gamepad_config.deadzone))
+        .find(|s| *s != Vec2::ZERO)
+        .unwrap_or(Vec2::ZERO);
 
-    let movement = Vec2::new(horizontal, vertical);
-    actions.player_movement = (movement != Vec2::ZERO).then_some(movement.normalize_or_zero());
+    (stick != Vec2::ZERO).then_some(stick)
 }
@@ -0,0 +1,259 @@
+//! On-screen touch controls: a virtual joystick + mood buttons.
+//!
+//! They only show up once a touch has been seen, and they feed [`Actions`]
+//! like any other input source. Menu / Game Over buttons need nothing extra:
+//! Bevy's UI `Interaction` already reacts to taps.
+
+use bevy::prelude::*;
+use bevy::ui::UiSystems;
+use bevy::window::PrimaryWindow;
+
+use crate::GameState;
+use crate::actions::{Actions, ActionsSystems, MoodIntent, set_actions};
+use crate::mood::{Mood, Moods};
+
+pub struct TouchControlsPlugin;
+
+/// Set once the player has touched the screen
+#[derive(Resource, Default)]
+pub struct TouchDetected(pub bool);
+
+#[derive(Component)]
+struct TouchControlsTag;
+
+#[derive(Component)]
+struct JoystickBase;
+
+#[derive(Component)]
+struct JoystickKnob;
+
+#[derive(Component)]
+struct TouchMoodButton(Mood);
+
+/// Touch input systems, for sources that must see the joystick's claim
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub(super) struct TouchInputSystems;
+
+/// Finger currently steering the joystick
+#[derive(Resource, Default)]
+pub(super) struct JoystickTouch(pub(super) Option<u64>);
+
+const JOYSTICK_SIZE: f32 = 170.0;
+const KNOB_SIZE: f32 = 70.0;
+/// Fraction of the joystick radius that is ignored
+const JOYSTICK_DEADZONE: f32 = 0.12;
+
+impl Plugin for TouchControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TouchDetected>()
+            .init_resource::<JoystickTouch>()
+            .add_systems(
+                PreUpdate,
+                (detect_touch, touch_joystick_actions, touch_mood_actions)
+                    .chain()
+                    .in_set(ActionsSystems)
+                    .in_set(TouchInputSystems)
+                    .after(set_actions)
+                    .after(UiSystems::Focus),
+            )
+            .add_systems(
+                Update,
+                (spawn_touch_controls, update_touch_visuals)
+                    .chain()
+                    .run_if(in_state(GameState::Countdown).or(in_state(GameState::Playing))),
+            )
+            // Off the Game Over card so its buttons get the taps; the next countdown respawns them
+            .add_systems(OnEnter(GameState::GameOver), despawn_touch_controls)
+            .add_systems(OnEnter(GameState::Menu), despawn_touch_controls);
+    }
+}
+
+fn detect_touch(touches: Res<Touches>, mut detected: ResMut<TouchDetected>) {
+    if !detected.0 && touches.iter().next().is_some() {
+        detected.0 = true;
+    }
+}
+
+fn spawn_touch_controls(
+    mut commands: Commands,
+    detected: Res<TouchDetected>,
+    moods: Moods,
+    q_existing: Query<(), With<TouchControlsTag>>,
+) {
+    if !detected.0 || !q_existing.is_empty() {
+        return;
+    }
+
+    // Joystick (bottom-left)
+    commands
+        .spawn((
+            TouchControlsTag,
+            JoystickBase,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(36.0),
+                bottom: Val::Px(36.0),
+                width: Val::Px(JOYSTICK_SIZE),
+                height: Val::Px(JOYSTICK_SIZE),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(2.0)),
+                border_radius: BorderRadius::all(Val::Px(999.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.08)),
+            BorderColor::all(Color::srgba(0.20, 0.90, 0.95, 0.45)),
+        ))
+        .with_child((
+            TouchControlsTag,
+            JoystickKnob,
+            Node {
+                width: Val::Px(KNOB_SIZE),
+                height: Val::Px(KNOB_SIZE),
+                border_radius: BorderRadius::all(Val::Px(999.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.20, 0.90, 0.95, 0.55)),
+        ));
+
+    // Mood buttons (bottom-right), one per mood in the mood file
+    commands
+        .spawn((
+            TouchControlsTag,
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(36.0),
+                bottom: Val::Px(36.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+        ))
+        .with_children(|col| {
+            for (i, def) in moods.all().iter().enumerate() {
+                col.spawn((
+                    TouchControlsTag,
+                    Button,
+                    TouchMoodButton(Mood(i)),
+                    Node {
+                        width: Val::Px(150.0),
+                        height: Val::Px(58.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(2.0)),
+                        border_radius: BorderRadius::all(Val::Px(14.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.10, 0.10, 0.12, 0.65)),
+                    BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
+                ))
+                .with_child((
+                    TouchControlsTag,
+                    Text::new(def.name.clone()),
+                    TextFont {
+                        font_size: 22.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            }
+        });
+}
+
+/// Joystick position in -1..1 (y up) for a touch, if it is over the joystick base
+fn joystick_vector(
+    touch_pos: Vec2,
+    scale_factor: f32,
+    node: &ComputedNode,
+    transform: &UiGlobalTransform,
+) -> Option<Vec2> {
+    // Touches are logical pixels, UI transforms are physical
+    let local = node.normalize_point(*transform, touch_pos * scale_factor)?;
+    Some(Vec2::new(local.x, -local.y) * 2.0)
+}
+
+fn touch_joystick_actions(
+    touches: Res<Touches>,
+    mut active: ResMut<JoystickTouch>,
+    mut actions: ResMut<Actions>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_base: Query<(&ComputedNode, &UiGlobalTransform), With<JoystickBase>>,
+) {
+    let Ok((node, transform)) = q_base.single() else {
+        active.0 = None;
+        return;
+    };
+    let scale_factor = q_window.single().map(|w| w.scale_factor()).unwrap_or(1.0);
+
+    // Claim a new finger only if it starts on the joystick
+    if active.0.is_none() {
+        active.0 = touches
+            .iter_just_pressed()
+            .find(|t| {
+                joystick_vector(t.position(), scale_factor, node, transform)
+                    .is_some_and(|v| v.length() <= 1.0)
+            })
+            .map(|t| t.id());
+    }
+
+    let Some(id) = active.0 else {
+        return;
+    };
+    let Some(touch) = touches.get_pressed(id) else {
+        active.0 = None;
+        return;
+    };
+
+    let Some(v) = joystick_vector(touch.position(), scale_factor, node, transform) else {
+        return;
+    };
+    let v = v.clamp_length_max(1.0);
+    if v.length() > JOYSTICK_DEADZONE {
+        actions.player_movement = Some(v);
+    }
+}
+
+fn touch_mood_actions(
+    mut actions: ResMut<Actions>,
+    q: Query<(&Interaction, &TouchMoodButton), Changed<Interaction>>,
+) {
+    for (i, b) in &q {
+        if *i == Interaction::Pressed {
+            actions.mood = Some(MoodIntent::Select(b.0));
+        }
+    }
+}
+
+fn update_touch_visuals(
+    actions: Res<Actions>,
+    active: Res<JoystickTouch>,
+    mood: Res<Mood>,
+    moods: Moods,
+    mut q_knob: Query<&mut UiTransform, With<JoystickKnob>>,
+    mut q_buttons: Query<(&TouchMoodButton, &mut BorderColor)>,
+) {
+    // Knob follows the stick, inside the base
+    let offset = match (active.0, actions.player_movement) {
+        (Some(_), Some(v)) => v * (JOYSTICK_SIZE - KNOB_SIZE) * 0.5,
+        _ => Vec2::ZERO,
+    };
+    for mut t in &mut q_knob {
+        t.translation = Val2::px(offset.x, -offset.y);
+    }
+
+    for (b, mut border) in &mut q_buttons {
+        *border = BorderColor::all(if b.0 == *mood {
+            moods
+                .get(b.0)
+                .map_or(Color::srgb(0.20, 0.90, 0.95), |m| m.color())
+        } else {
+            Color::srgb(0.22, 0.22, 0.28)
+        });
+    }
+}
+
+fn despawn_touch_controls(mut commands: Commands, q: Query<Entity, With<TouchControlsTag>>) {
+    for e in &q {
+        commands.entity(e).despawn();
+    }
+}
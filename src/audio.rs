@@ -1,10 +1,252 @@
+use std::time::Duration;
+
 use bevy::prelude::*;
+use bevy_kira_audio::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::loading::AudioAssets;
+use crate::mood::{Mood, MoodDef, Moods};
+use crate::player::LowTimeAlerted;
+use crate::storage;
+use crate::{GameState, PauseState};
 
 pub struct InternalAudioPlugin;
 
+/// Kira channel for the music loop
+#[derive(Resource)]
+pub struct MusicChannel;
+
+/// Kira channel for one-shot sounds
+#[derive(Resource)]
+pub struct SfxChannel;
+
+/// Volume levels (0..1, linear) + mute. Persisted under the `audio` key.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct AudioSettings {
+    pub master: f32,
+    pub music: f32,
+    pub sfx: f32,
+    pub muted: bool,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            master: 0.8,
+            music: 0.7,
+            sfx: 0.9,
+            muted: false,
+        }
+    }
+}
+
+impl AudioSettings {
+    fn channel_volume(&self, level: f32) -> Decibels {
+        if self.muted {
+            Decibels::SILENCE
+        } else {
+            amplitude_to_decibels(self.master * level)
+        }
+    }
+}
+
+const AUDIO_KEY: &str = "audio";
+
+/// Persist the current volume levels + mute state
+pub fn save_audio_settings(settings: &AudioSettings) {
+    storage::save(AUDIO_KEY, settings);
+}
+
+fn amplitude_to_decibels(amplitude: f32) -> Decibels {
+    if amplitude <= 0.001 {
+        Decibels::SILENCE
+    } else {
+        Decibels(20.0 * amplitude.log10())
+    }
+}
+
+/// One-shot sounds. Gameplay systems write these, the audio plugin plays them.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoundEffect {
+    MemoryCollected,
+    CountdownTick,
+    CountdownGo,
+    HurryUp,
+    WallBump,
+}
+
+/// The running music loop (one per run)
+#[derive(Resource)]
+struct MusicInstance(Handle<AudioInstance>);
+
+const MUSIC_FADE: Duration = Duration::from_millis(400);
+/// How long the music takes to glide into a new mood
+const MOOD_CROSSFADE: Duration = Duration::from_millis(350);
+/// Extra tempo once HURRY UP kicks in
+const LOW_TIME_TEMPO: f64 = 1.2;
+
+/// How the music loop is played for a given mood
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MusicStyle {
+    playback_rate: f64,
+    /// -1 = left, 0 = centre, 1 = right
+    panning: f32,
+}
+
+impl MusicStyle {
+    /// The mood's own `music` entry from the mood file
+    fn for_mood(mood: Option<&MoodDef>, low_time: bool) -> Self {
+        let music = mood.map(|m| m.music).unwrap_or_default();
+        let mut style = Self {
+            playback_rate: music.playback_rate,
+            panning: music.panning,
+        };
+        if low_time {
+            style.playback_rate *= LOW_TIME_TEMPO;
+        }
+        style
+    }
+}
+
 impl Plugin for InternalAudioPlugin {
-    fn build(&self, _app: &mut App) {
-        // Intentionally empty for now.
-        // (Your previous .ogg loading was failing due to loader/feature mismatch.)
+    fn build(&self, app: &mut App) {
+        let mut settings = storage::load::<AudioSettings>(AUDIO_KEY).unwrap_or_default();
+        for level in [&mut settings.master, &mut settings.music, &mut settings.sfx] {
+            *level = level.clamp(0.0, 1.0);
+        }
+
+        app.add_plugins(AudioPlugin)
+            .add_audio_channel::<MusicChannel>()
+            .add_audio_channel::<SfxChannel>()
+            .insert_resource(settings)
+            .add_message::<SoundEffect>()
+            .add_systems(
+                Update,
+                apply_volumes.run_if(resource_changed::<AudioSettings>),
+            )
+            .add_systems(OnEnter(GameState::Playing), start_music)
+            .add_systems(OnEnter(GameState::GameOver), pause_music)
+            .add_systems(OnExit(PauseState::Running), pause_music)
+            .add_systems(
+                OnEnter(PauseState::Running),
+                resume_music.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(Update, adapt_music.run_if(in_state(GameState::Playing)))
+            .add_systems(
+                Update,
+                play_sound_effects.run_if(resource_exists::<AudioAssets>),
+            );
+    }
+}
+
+fn apply_volumes(
+    settings: Res<AudioSettings>,
+    music: Res<AudioChannel<MusicChannel>>,
+    sfx: Res<AudioChannel<SfxChannel>>,
+) {
+    music.set_volume(settings.channel_volume(settings.music));
+    sfx.set_volume(settings.channel_volume(settings.sfx));
+}
+
+fn start_music(
+    mut commands: Commands,
+    audio: Res<AudioChannel<MusicChannel>>,
+    assets: Res<AudioAssets>,
+    music: Option<Res<MusicInstance>>,
+    mut instances: ResMut<Assets<AudioInstance>>,
+) {
+    // Every run starts the loop from the top
+    if let Some(music) = music
+        && let Some(instance) = instances.get_mut(&music.0)
+    {
+        instance.stop(AudioTween::linear(MUSIC_FADE));
+    }
+
+    let handle = audio
+        .play(assets.music.clone())
+        .looped()
+        .fade_in(AudioTween::linear(MUSIC_FADE))
+        .handle();
+    commands.insert_resource(MusicInstance(handle));
+}
+
+fn pause_music(music: Option<Res<MusicInstance>>, mut instances: ResMut<Assets<AudioInstance>>) {
+    let Some(music) = music else { return };
+    if let Some(instance) = instances.get_mut(&music.0) {
+        instance.pause(AudioTween::linear(MUSIC_FADE));
+    }
+}
+
+fn resume_music(music: Option<Res<MusicInstance>>, mut instances: ResMut<Assets<AudioInstance>>) {
+    let Some(music) = music else { return };
+    if let Some(instance) = instances.get_mut(&music.0) {
+        instance.resume(AudioTween::linear(MUSIC_FADE));
     }
 }
+
+/// Glide rate / panning towards the current mood (and tempo up when time is low)
+fn adapt_music(
+    mood: Res<Mood>,
+    moods: Moods,
+    alerted: Res<LowTimeAlerted>,
+    music: Option<Res<MusicInstance>>,
+    mut instances: ResMut<Assets<AudioInstance>>,
+    mut applied: Local<Option<(AssetId<AudioInstance>, MusicStyle)>>,
+) {
+    let Some(music) = music else { return };
+    let style = MusicStyle::for_mood(moods.get(*mood), alerted.0);
+    if *applied == Some((music.0.id(), style)) {
+        return;
+    }
+
+    // The instance shows up a frame after `play`, so keep trying until it exists
+    let Some(instance) = instances.get_mut(&music.0) else {
+        return;
+    };
+    instance.set_playback_rate(style.playback_rate, AudioTween::linear(MOOD_CROSSFADE));
+    instance.set_panning(style.panning, AudioTween::linear(MOOD_CROSSFADE));
+    *applied = Some((music.0.id(), style));
+}
+
+fn play_sound_effects(
+    mut sounds: MessageReader<SoundEffect>,
+    audio: Res<AudioChannel<SfxChannel>>,
+    assets: Res<AudioAssets>,
+) {
+    // A still-locked browser would queue these and play them all at once on unlock
+    if !web_audio_unlocked() {
+        sounds.clear();
+        return;
+    }
+
+    for sound in sounds.read() {
+        let source = match sound {
+            SoundEffect::MemoryCollected => &assets.memory,
+            SoundEffect::CountdownTick => &assets.tick,
+            SoundEffect::CountdownGo => &assets.go,
+            SoundEffect::HurryUp => &assets.hurry,
+            SoundEffect::WallBump => &assets.bump,
+        };
+        audio.play(source.clone());
+    }
+}
+
+/// Browsers keep audio suspended until the first user gesture. `build/web/sound.js`
+/// resumes the AudioContext then and flips `window.feverDreamAudioUnlocked`.
+#[cfg(target_arch = "wasm32")]
+fn web_audio_unlocked() -> bool {
+    let Some(window) = web_sys::window() else {
+        return true;
+    };
+    // Missing flag = page without sound.js, nothing is holding audio back
+    js_sys::Reflect::get(&window, &"feverDreamAudioUnlocked".into())
+        .ok()
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn web_audio_unlocked() -> bool {
+    true
+}
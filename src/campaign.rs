@@ -0,0 +1,139 @@
+//! Campaign progress: which levels are cleared and their best clear times.
+//!
+//! Levels are keyed by their `name`, so reordering `LevelAssets::campaign`
+//! keeps the records. Progress is persisted under the `campaign` key.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::level::{CurrentLevel, LevelData};
+use crate::player::{RunEnded, RunOutcome};
+use crate::storage;
+use crate::{GameConfig, MazeSource};
+
+pub struct CampaignPlugin;
+
+const CAMPAIGN_KEY: &str = "campaign";
+
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct CampaignProgress {
+    /// Best clear time in seconds; a level is cleared once it has one
+    best_times: HashMap<String, f32>,
+}
+
+impl CampaignProgress {
+    pub fn best_time(&self, level: &str) -> Option<f32> {
+        self.best_times.get(level).copied()
+    }
+
+    pub fn is_cleared(&self, level: &str) -> bool {
+        self.best_times.contains_key(level)
+    }
+
+    /// The first level is always open, the others once the level before is cleared
+    pub fn is_unlocked(&self, previous: Option<&str>) -> bool {
+        previous.is_none_or(|p| self.is_cleared(p))
+    }
+
+    /// Returns true for a new best time
+    fn record_clear(&mut self, level: &str, time: f32) -> bool {
+        match self.best_times.get_mut(level) {
+            Some(best) if *best <= time => false,
+            Some(best) => {
+                *best = time;
+                true
+            }
+            None => {
+                self.best_times.insert(level.to_string(), time);
+                true
+            }
+        }
+    }
+}
+
+impl Plugin for CampaignPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(storage::load::<CampaignProgress>(CAMPAIGN_KEY).unwrap_or_default())
+            .add_systems(Update, record_campaign_clear);
+    }
+}
+
+/// Campaign level that "Next Level" leads to, if the run just cleared one
+pub(crate) fn next_level(
+    config: &GameConfig,
+    outcome: RunOutcome,
+    campaign_len: usize,
+) -> Option<usize> {
+    let next = config.level + 1;
+    (outcome.is_victory() && config.maze == MazeSource::Campaign && next < campaign_len)
+        .then_some(next)
+}
+
+fn record_campaign_clear(
+    mut ended: MessageReader<RunEnded>,
+    config: Res<GameConfig>,
+    current_level: Option<Res<CurrentLevel>>,
+    levels: Res<Assets<LevelData>>,
+    mut progress: ResMut<CampaignProgress>,
+) {
+    for RunEnded(outcome) in ended.read() {
+        if !outcome.is_victory() || config.maze != MazeSource::Campaign {
+            continue;
+        }
+        let Some(level) = current_level.as_ref().and_then(|c| levels.get(&c.handle)) else {
+            continue;
+        };
+
+        if progress.record_clear(&level.name, outcome.time) {
+            storage::save(CAMPAIGN_KEY, &*progress);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::RunResult;
+
+    #[test]
+    fn only_a_faster_clear_replaces_the_best() {
+        let mut progress = CampaignProgress::default();
+        assert!(progress.is_unlocked(None));
+        assert!(!progress.is_unlocked(Some("dream")));
+
+        assert!(progress.record_clear("dream", 40.0));
+        assert!(progress.is_unlocked(Some("dream")));
+        assert!(!progress.record_clear("dream", 45.0));
+        assert!(!progress.record_clear("dream", 40.0));
+        assert!(progress.record_clear("dream", 32.5));
+        assert_eq!(progress.best_time("dream"), Some(32.5));
+    }
+
+    #[test]
+    fn last_level_has_no_next_level() {
+        let won = RunOutcome {
+            result: RunResult::Victory,
+            time: 30.0,
+            remaining: 10.0,
+            collected: 5,
+            missed: 0,
+            time_bonus: 100,
+            score: 600,
+        };
+        let mut config = GameConfig::default();
+        assert_eq!(next_level(&config, won, 3), Some(1));
+
+        config.level = 2;
+        assert_eq!(next_level(&config, won, 3), None);
+
+        config.level = 0;
+        let lost = RunOutcome {
+            result: RunResult::TimeUp,
+            ..won
+        };
+        assert_eq!(next_level(&config, lost, 3), None);
+    }
+}
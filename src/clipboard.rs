@@ -0,0 +1,99 @@
+//! Clipboard paste for text fields.
+//!
+//! Desktop reads the system clipboard when the paste shortcut is pressed.
+//! Browsers only hand out clipboard text inside a `paste` DOM event, so the web
+//! build queues those events and returns them on the next poll instead, but
+//! only while a text field has focus ([`set_focused`]); pastes anywhere else are dropped.
+//! Other platforms (Android / iOS) have no paste support yet.
+
+/// Call once at startup (only does something on web)
+pub fn install() {
+    #[cfg(target_arch = "wasm32")]
+    web::install();
+}
+
+/// Tell the clipboard whether a text field is focused. Losing or gaining focus
+/// also drops anything queued, so a paste never lands in the wrong place.
+pub fn set_focused(focused: bool) {
+    #[cfg(target_arch = "wasm32")]
+    web::set_focused(focused);
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = focused;
+}
+
+/// Text to paste this frame, if any. `shortcut_pressed` = Ctrl/Cmd+V was just pressed.
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(target_os = "android"),
+    not(target_os = "ios")
+))]
+pub fn poll_paste(shortcut_pressed: bool) -> Option<String> {
+    if !shortcut_pressed {
+        return None;
+    }
+
+    match arboard::Clipboard::new().and_then(|mut c| c.get_text()) {
+        Ok(text) => Some(text),
+        Err(e) => {
+            bevy::log::warn!("Clipboard paste failed: {e}");
+            None
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn poll_paste(_shortcut_pressed: bool) -> Option<String> {
+    web::take_pasted()
+}
+
+#[cfg(any(target_os = "android", target_os = "ios"))]
+pub fn poll_paste(_shortcut_pressed: bool) -> Option<String> {
+    None
+}
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen::closure::Closure;
+
+    static PASTED: Mutex<Option<String>> = Mutex::new(None);
+    static FOCUSED: AtomicBool = AtomicBool::new(false);
+
+    pub fn install() {
+        let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+            return;
+        };
+
+        let on_paste =
+            Closure::<dyn FnMut(web_sys::ClipboardEvent)>::new(|event: web_sys::ClipboardEvent| {
+                if !FOCUSED.load(Ordering::Relaxed) {
+                    return;
+                }
+                let text = event
+                    .clipboard_data()
+                    .and_then(|data| data.get_data("text/plain").ok());
+                if let (Some(text), Ok(mut slot)) = (text, PASTED.lock()) {
+                    *slot = Some(text);
+                }
+            });
+
+        let _ =
+            document.add_event_listener_with_callback("paste", on_paste.as_ref().unchecked_ref());
+        // Lives for the whole page
+        on_paste.forget();
+    }
+
+    pub fn set_focused(focused: bool) {
+        FOCUSED.store(focused, Ordering::Relaxed);
+        if let Ok(mut slot) = PASTED.lock() {
+            *slot = None;
+        }
+    }
+
+    pub fn take_pasted() -> Option<String> {
+        PASTED.lock().ok()?.take()
+    }
+}
@@ -0,0 +1,257 @@
+//! Colliders, collision layers and swept AABB movement.
+//!
+//! Shapes come from the [`Collider`] component, never from sprites, so the art
+//! can change freely. Walls are solid: the player box travels along its step and
+//! stops at the first wall it would enter, then slides along it with what is left
+//! of the step, so a long step (a frame hitch) can't skip over a thin wall and a
+//! corner stops one axis per wall instead of jittering. Pickups, hazards and
+//! triggers are sensors: touching one only sends an [`Overlap`] message.
+
+use std::ops::BitOr;
+
+use bevy::prelude::*;
+
+use crate::player::Velocity;
+
+/// Bit set of collision layers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Layers(u8);
+
+impl Layers {
+    pub const PLAYER: Layers = Layers(1 << 0);
+    pub const WALL: Layers = Layers(1 << 1);
+    pub const PICKUP: Layers = Layers(1 << 2);
+    pub const HAZARD: Layers = Layers(1 << 3);
+    pub const TRIGGER: Layers = Layers(1 << 4);
+    /// Layers that report overlaps instead of blocking
+    pub const SENSORS: Layers = Layers(Self::PICKUP.0 | Self::HAZARD.0 | Self::TRIGGER.0);
+
+    pub fn intersects(self, other: Layers) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+impl BitOr for Layers {
+    type Output = Layers;
+
+    fn bitor(self, rhs: Layers) -> Layers {
+        Layers(self.0 | rhs.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColliderShape {
+    Aabb { half_extents: Vec2 },
+    Circle { radius: f32 },
+}
+
+impl ColliderShape {
+    /// Half size of the bounding box
+    pub fn half_extents(self) -> Vec2 {
+        match self {
+            ColliderShape::Aabb { half_extents } => half_extents,
+            ColliderShape::Circle { radius } => Vec2::splat(radius),
+        }
+    }
+}
+
+/// Collision shape centred on the entity's translation
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Collider {
+    pub shape: ColliderShape,
+    /// What this collider is
+    pub layers: Layers,
+    /// What it collides with / reports; both sides have to agree
+    pub mask: Layers,
+}
+
+impl Collider {
+    pub fn aabb(size: Vec2, layers: Layers, mask: Layers) -> Self {
+        Self {
+            shape: ColliderShape::Aabb {
+                half_extents: size * 0.5,
+            },
+            layers,
+            mask,
+        }
+    }
+
+    pub fn circle(radius: f32, layers: Layers, mask: Layers) -> Self {
+        Self {
+            shape: ColliderShape::Circle { radius },
+            layers,
+            mask,
+        }
+    }
+
+    pub fn interacts_with(&self, other: &Collider) -> bool {
+        self.mask.intersects(other.layers) && other.mask.intersects(self.layers)
+    }
+}
+
+/// A collider touched a sensor (pickup, hazard or trigger) this frame
+#[derive(Message, Debug, Clone, Copy)]
+pub struct Overlap {
+    pub entity: Entity,
+    pub sensor: Entity,
+    pub layers: Layers,
+}
+
+/// Whether two shapes at `a` and `b` touch
+pub fn shapes_overlap(a: Vec2, shape_a: ColliderShape, b: Vec2, shape_b: ColliderShape) -> bool {
+    use ColliderShape::*;
+    match (shape_a, shape_b) {
+        (Aabb { half_extents: ha }, Aabb { half_extents: hb }) => {
+            ((a - b).abs() - (ha + hb)).cmple(Vec2::ZERO).all()
+        }
+        (Circle { radius: ra }, Circle { radius: rb }) => {
+            a.distance_squared(b) <= (ra + rb).powi(2)
+        }
+        (Aabb { half_extents }, Circle { radius }) => {
+            let closest = b.clamp(a - half_extents, a + half_extents);
+            closest.distance_squared(b) <= radius * radius
+        }
+        (Circle { .. }, Aabb { .. }) => shapes_overlap(b, shape_b, a, shape_a),
+    }
+}
+
+/// Report every sensor the moving colliders (the ones with a velocity) touch
+pub fn detect_overlaps(
+    movers: Query<(Entity, &Transform, &Collider), With<Velocity>>,
+    sensors: Query<(Entity, &Transform, &Collider)>,
+    mut overlaps: MessageWriter<Overlap>,
+) {
+    for (entity, t, collider) in &movers {
+        let pos = t.translation.truncate();
+        for (sensor, st, sc) in &sensors {
+            if sensor == entity
+                || !sc.layers.intersects(Layers::SENSORS)
+                || !collider.interacts_with(sc)
+            {
+                continue;
+            }
+            if shapes_overlap(pos, collider.shape, st.translation.truncate(), sc.shape) {
+                overlaps.write(Overlap {
+                    entity,
+                    sensor,
+                    layers: sc.layers,
+                });
+            }
+        }
+    }
+}
+
+/// Gap kept between the player and a wall after a hit
+const SKIN: f32 = 0.01;
+/// Slides per step; each hit blocks one axis, so a corner needs two
+const MAX_SLIDES: usize = 4;
+
+/// Where a swept move ended
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sweep {
+    pub pos: Vec2,
+    /// Push-out direction per axis of the walls that were hit (0 = no hit)
+    pub normal: Vec2,
+}
+
+/// Move a box (centre `pos`, half size `half`) by `delta`, sliding along `walls`.
+/// Only boxes block; solid circles are not supported.
+pub fn sweep(mut pos: Vec2, half: Vec2, mut delta: Vec2, walls: &[Rect]) -> Sweep {
+    let mut normal = Vec2::ZERO;
+
+    for _ in 0..MAX_SLIDES {
+        if delta == Vec2::ZERO {
+            break;
+        }
+
+        // Minkowski sum: the box becomes a point, every wall grows by its half size
+        let hit = walls
+            .iter()
+            .filter_map(|w| {
+                let grown = Rect {
+                    min: w.min - half,
+                    max: w.max + half,
+                };
+                point_vs_rect(pos, delta, grown)
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0));
+
+        let Some((t, n)) = hit else {
+            pos += delta;
+            break;
+        };
+
+        pos += delta * t + n * SKIN;
+        delta = stop_against(delta * (1.0 - t), n);
+        if n.x != 0.0 {
+            normal.x = n.x;
+        }
+        if n.y != 0.0 {
+            normal.y = n.y;
+        }
+    }
+
+    Sweep { pos, normal }
+}
+
+/// First time (0..=1) a point moving by `delta` enters `rect`, with the face normal.
+/// Moving along a face or starting inside is not a hit.
+fn point_vs_rect(p: Vec2, delta: Vec2, rect: Rect) -> Option<(f32, Vec2)> {
+    let mut t_near = f32::NEG_INFINITY;
+    let mut t_far = f32::INFINITY;
+    let mut normal = Vec2::ZERO;
+
+    for axis in 0..2 {
+        let (p, d) = (p[axis], delta[axis]);
+        let (min, max) = (rect.min[axis], rect.max[axis]);
+        if d == 0.0 {
+            if p <= min || p >= max {
+                return None;
+            }
+            continue;
+        }
+
+        let (t1, t2) = ((min - p) / d, (max - p) / d);
+        let (near, far) = (t1.min(t2), t1.max(t2));
+        if near > t_near {
+            t_near = near;
+            normal = Vec2::ZERO;
+            normal[axis] = -d.signum();
+        } else if near == t_near {
+            // Exactly into a corner: both faces block
+            normal[axis] = -d.signum();
+        }
+        t_far = t_far.min(far);
+    }
+
+    ((0.0..=1.0).contains(&t_near) && t_near < t_far).then_some((t_near, normal))
+}
+
+/// Push a box out of walls it already overlaps (spawned or reloaded into one),
+/// along the shallower axis of each. Returns the new centre and the push direction.
+pub fn depenetrate(mut pos: Vec2, half: Vec2, walls: &[Rect]) -> (Vec2, Vec2) {
+    let mut normal = Vec2::ZERO;
+    for wall in walls {
+        let d = wall.center() - pos;
+        let overlap = half + wall.half_size() - d.abs();
+        if overlap.x <= 0.0 || overlap.y <= 0.0 {
+            continue;
+        }
+
+        if overlap.x < overlap.y {
+            normal.x = -d.x.signum();
+            pos.x += normal.x * (overlap.x + SKIN);
+        } else {
+            normal.y = -d.y.signum();
+            pos.y += normal.y * (overlap.y + SKIN);
+        }
+    }
+    (pos, normal)
+}
+
+/// Zero the velocity on every axis where it points into a contact.
+/// `normal` points out of the contact per axis (0 = no contact); only the signs matter.
+pub fn stop_against(velocity: Vec2, normal: Vec2) -> Vec2 {
+    let keep = |v: f32, n: f32| if v * n < 0.0 { 0.0 } else { v };
+    Vec2::new(keep(velocity.x, normal.x), keep(velocity.y, normal.y))
+}
@@ -0,0 +1,472 @@
+use bevy::prelude::*;
+
+use crate::GameState;
+use crate::actions::{
+    Actions, GameControl, GamepadConfig, InputBinding, InputBindings, save_controls,
+};
+use crate::mood::{Mood, Moods};
+
+/// Rebinding screen reached from the main menu
+pub struct ControlsMenuPlugin;
+
+#[derive(Component)]
+struct ControlsTag;
+
+#[derive(Component)]
+struct RebindButton(GameControl);
+
+#[derive(Component)]
+struct BindingText(GameControl);
+
+#[derive(Component)]
+struct ResetButton;
+
+#[derive(Component)]
+struct RebindNotice;
+
+/// Instructions; they name the current Cancel key
+#[derive(Component)]
+struct RebindHint;
+
+#[derive(Component)]
+struct BackButton;
+
+/// Which control (if any) is waiting for the next key / button press
+#[derive(Resource, Default)]
+struct RebindState {
+    waiting: Option<GameControl>,
+    /// What the last rebind took from other controls
+    notice: String,
+}
+
+impl Plugin for ControlsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RebindState>()
+            .add_systems(OnEnter(GameState::Controls), setup_controls_menu)
+            .add_systems(
+                Update,
+                (
+                    controls_back_input,
+                    capture_rebind,
+                    controls_buttons,
+                    refresh_binding_texts,
+                    controls_visuals,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Controls)),
+            )
+            .add_systems(OnExit(GameState::Controls), cleanup_controls_menu);
+    }
+}
+
+fn setup_controls_menu(mut commands: Commands, mut rebind: ResMut<RebindState>, moods: Moods) {
+    rebind.waiting = None;
+    rebind.notice.clear();
+    // Dev builds also list the editor keys, in a third column
+    let (columns, width) = if cfg!(feature = "dev") {
+        (3, 1240.0)
+    } else {
+        (2, 980.0)
+    };
+
+    commands
+        .spawn((
+            ControlsTag,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.05, 0.05, 0.06)),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                ControlsTag,
+                Node {
+                    width: Val::Px(width),
+                    padding: UiRect::all(Val::Px(24.0)),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(12.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    border_radius: BorderRadius::all(Val::Px(18.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.09, 0.09, 0.11)),
+                BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
+            ))
+            .with_children(|card| {
+                card.spawn((
+                    ControlsTag,
+                    Text::new("CONTROLS"),
+                    TextFont {
+                        font_size: 40.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.95, 0.95, 0.98)),
+                ));
+
+                card.spawn((
+                    ControlsTag,
+                    RebindHint,
+                    Text::new(""),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.76, 0.76, 0.80)),
+                ));
+
+                card.spawn((
+                    ControlsTag,
+                    RebindNotice,
+                    Text::new(""),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.95, 0.85, 0.35)),
+                ));
+
+                // Columns of controls
+                card.spawn((
+                    ControlsTag,
+                    Node {
+                        display: Display::Grid,
+                        grid_template_columns: RepeatedGridTrack::flex(columns, 1.0),
+                        column_gap: Val::Px(18.0),
+                        row_gap: Val::Px(6.0),
+                        ..default()
+                    },
+                ))
+                .with_children(|grid| {
+                    for control in GameControl::ALL {
+                        if control.is_editor() && !cfg!(feature = "dev") {
+                            continue;
+                        }
+                        // Mood slots are named after the mood file; empty ones are hidden
+                        let label = match control {
+                            GameControl::Mood(slot) => match moods.get(Mood(usize::from(slot))) {
+                                Some(def) => format!("Mood: {}", def.name),
+                                None => continue,
+                            },
+                            _ => control.label(),
+                        };
+                        spawn_control_row(grid, control, label);
+                    }
+                });
+
+                card.spawn((
+                    ControlsTag,
+                    Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(12.0),
+                        margin: UiRect::top(Val::Px(10.0)),
+                        ..default()
+                    },
+                ))
+                .with_children(|row| {
+                    row.spawn((
+                        ControlsTag,
+                        Button,
+                        BackButton,
+                        Node {
+                            width: Val::Px(200.0),
+                            height: Val::Px(52.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(1.0)),
+                            border_radius: BorderRadius::all(Val::Px(14.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.12, 0.18, 0.20)),
+                        BorderColor::all(Color::srgb(0.20, 0.60, 0.65)),
+                    ))
+                    .with_child((
+                        ControlsTag,
+                        Text::new("BACK"),
+                        TextFont {
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+
+                    row.spawn((
+                        ControlsTag,
+                        Button,
+                        ResetButton,
+                        Node {
+                            width: Val::Px(240.0),
+                            height: Val::Px(52.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(1.0)),
+                            border_radius: BorderRadius::all(Val::Px(14.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.16, 0.12, 0.14)),
+                        BorderColor::all(Color::srgb(0.55, 0.22, 0.28)),
+                    ))
+                    .with_child((
+                        ControlsTag,
+                        Text::new("RESET DEFAULTS"),
+                        TextFont {
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            });
+        });
+}
+
+fn spawn_control_row(grid: &mut ChildSpawnerCommands, control: GameControl, label: String) {
+    grid.spawn((
+        ControlsTag,
+        Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(10.0),
+            ..default()
+        },
+    ))
+    .with_children(|row| {
+        row.spawn((
+            ControlsTag,
+            Text::new(label),
+            TextFont {
+                font_size: 17.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.86, 0.86, 0.90)),
+            Node {
+                width: Val::Px(150.0),
+                ..default()
+            },
+        ));
+
+        row.spawn((
+            ControlsTag,
+            BindingText(control),
+            Text::new(""),
+            TextFont {
+                font_size: 15.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.35, 0.9, 0.95)),
+            Node {
+                flex_grow: 1.0,
+                ..default()
+            },
+        ));
+
+        row.spawn((
+            ControlsTag,
+            Button,
+            RebindButton(control),
+            Node {
+                width: Val::Px(86.0),
+                height: Val::Px(30.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(1.0)),
+                border_radius: BorderRadius::all(Val::Px(10.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.14, 0.14, 0.16)),
+            BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
+        ))
+        .with_child((
+            ControlsTag,
+            Text::new("Rebind"),
+            TextFont {
+                font_size: 15.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+/// Cancel leaves the screen, unless a rebind is in progress (then it only cancels that)
+fn controls_back_input(
+    actions: Res<Actions>,
+    rebind: Res<RebindState>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if rebind.waiting.is_none() && actions.cancel {
+        next_state.set(GameState::Menu);
+    }
+}
+
+fn capture_rebind(
+    actions: Res<Actions>,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut rebind: ResMut<RebindState>,
+    mut bindings: ResMut<InputBindings>,
+    gamepad_config: Res<GamepadConfig>,
+) {
+    let Some(control) = rebind.waiting else {
+        return;
+    };
+
+    // Whatever Cancel is bound to backs out, so that key can't be picked up here
+    if actions.cancel {
+        rebind.waiting = None;
+        return;
+    }
+
+    let pressed = keys
+        .get_just_pressed()
+        .next()
+        .map(|k| InputBinding::Key(*k))
+        .or_else(|| {
+            gamepads
+                .iter()
+                .find_map(|g| g.get_just_pressed().next())
+                .map(|b| InputBinding::Gamepad(*b))
+        });
+
+    let Some(binding) = pressed else {
+        return;
+    };
+
+    // A key can only do one thing: whoever had it gets this control's old one
+    let swapped = bindings.rebind(control, binding);
+    rebind.notice = if swapped.is_empty() {
+        String::new()
+    } else {
+        let from: Vec<String> = swapped.iter().map(|c| c.label()).collect();
+        format!("{} was taken from {}", binding.label(), from.join(", "))
+    };
+    save_controls(&bindings, &gamepad_config);
+    rebind.waiting = None;
+}
+
+fn controls_buttons(
+    q: Query<
+        (
+            &Interaction,
+            Option<&RebindButton>,
+            Option<&ResetButton>,
+            Option<&BackButton>,
+        ),
+        (With<Button>, Changed<Interaction>),
+    >,
+    mut rebind: ResMut<RebindState>,
+    mut bindings: ResMut<InputBindings>,
+    gamepad_config: Res<GamepadConfig>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for (i, rebind_button, reset, back) in &q {
+        if *i != Interaction::Pressed {
+            continue;
+        }
+
+        if let Some(r) = rebind_button {
+            rebind.waiting = Some(r.0);
+            rebind.notice.clear();
+        } else if reset.is_some() {
+            *bindings = InputBindings::default();
+            save_controls(&bindings, &gamepad_config);
+            rebind.waiting = None;
+            rebind.notice.clear();
+        } else if back.is_some() {
+            next_state.set(GameState::Menu);
+        }
+    }
+}
+
+fn refresh_binding_texts(
+    bindings: Res<InputBindings>,
+    rebind: Res<RebindState>,
+    mut q: Query<(&BindingText, &mut Text, &mut TextColor)>,
+    mut q_notice: Query<&mut Text, (With<RebindNotice>, Without<BindingText>)>,
+    mut q_hint: Query<
+        &mut Text,
+        (
+            With<RebindHint>,
+            Without<BindingText>,
+            Without<RebindNotice>,
+        ),
+    >,
+) {
+    if !bindings.is_changed() && !rebind.is_changed() {
+        return;
+    }
+
+    for mut text in &mut q_hint {
+        *text = Text::new(format!(
+            "Click Rebind, then press a key or gamepad button. {} cancels a rebind.",
+            bindings.primary_label(GameControl::Cancel)
+        ));
+    }
+
+    for mut text in &mut q_notice {
+        *text = Text::new(rebind.notice.clone());
+    }
+
+    for (b, mut text, mut color) in &mut q {
+        if rebind.waiting == Some(b.0) {
+            *text = Text::new("press a key…");
+            color.0 = Color::srgb(0.95, 0.85, 0.35);
+        } else {
+            *text = Text::new(bindings.describe(b.0));
+            color.0 = Color::srgb(0.35, 0.9, 0.95);
+        }
+    }
+}
+
+fn controls_visuals(
+    rebind: Res<RebindState>,
+    mut q: Query<
+        (
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            Option<&RebindButton>,
+        ),
+        (With<Button>, With<ControlsTag>),
+    >,
+) {
+    let dim_border = Color::srgb(0.22, 0.22, 0.28);
+    let cyan = Color::srgb(0.20, 0.90, 0.95);
+    let purple = Color::srgb(0.85, 0.25, 0.95);
+
+    for (i, mut bg, mut border, rebind_button) in &mut q {
+        // Back / Reset keep the colors they were spawned with
+        let Some(r) = rebind_button else {
+            continue;
+        };
+
+        let waiting = rebind.waiting == Some(r.0);
+        match *i {
+            Interaction::Pressed => {
+                bg.0 = Color::srgb(0.24, 0.24, 0.30);
+                *border = BorderColor::all(purple);
+            }
+            Interaction::Hovered => {
+                bg.0 = Color::srgb(0.20, 0.20, 0.24);
+                *border = BorderColor::all(cyan);
+            }
+            Interaction::None if waiting => {
+                bg.0 = Color::srgb(0.20, 0.18, 0.10);
+                *border = BorderColor::all(Color::srgb(0.95, 0.85, 0.35));
+            }
+            Interaction::None => {
+                bg.0 = Color::srgb(0.14, 0.14, 0.16);
+                *border = BorderColor::all(dim_border);
+            }
+        }
+    }
+}
+
+fn cleanup_controls_menu(mut commands: Commands, q: Query<Entity, With<ControlsTag>>) {
+    for e in &q {
+        commands.entity(e).despawn();
+    }
+}
@@ -0,0 +1,440 @@
+//! Level editor (dev builds only).
+//!
+//! Drag out walls, drop memories and the player start, resize the arena and
+//! save the result as a level file. The preview is built with the same
+//! `spawn_level` / `spawn_memory` code a real run uses, and the test play key
+//! starts a countdown on the edited layout.
+
+use std::path::Path;
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::actions::{Actions, EditorIntent, GameControl, InputBindings};
+use crate::level::{CurrentLevel, LevelData, MemorySpawn, WallData};
+use crate::level_validation;
+use crate::loading::LevelAssets;
+use crate::player::{MEMORY_SIZE, PLAYER_SIZE, spawn_level, spawn_memory};
+use crate::{Difficulty, GameConfig, GameState, MazeSource};
+
+pub struct EditorPlugin;
+
+/// Where Save writes to (relative to the crate root, like `cargo run`)
+const SAVE_PATH: &str = "assets/levels/edited.level.ron";
+const GRID: f32 = 20.0;
+/// Arena half extents the editor allows (the window is 1280x720)
+const MIN_HALF_EXTENTS: Vec2 = Vec2::new(200.0, 120.0);
+const MAX_HALF_EXTENTS: Vec2 = Vec2::new(600.0, 320.0);
+/// Drags smaller than this on either axis are ignored
+const MIN_WALL: f32 = 10.0;
+
+#[derive(Component, Clone)]
+struct EditorEntity;
+
+#[derive(Component)]
+struct EditorStatusText;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Tool {
+    Wall,
+    Memory,
+    Start,
+}
+
+impl Tool {
+    fn label(self) -> &'static str {
+        match self {
+            Tool::Wall => "Wall",
+            Tool::Memory => "Memory",
+            Tool::Start => "Player start",
+        }
+    }
+}
+
+/// The layout being edited. Kept between visits so a test play can come back to it.
+#[derive(Resource)]
+struct EditorLayout(LevelData);
+
+#[derive(Resource)]
+struct EditorTools {
+    tool: Tool,
+    snap: bool,
+    /// Difficulty new memories show up from
+    memory_from: Difficulty,
+    drag_start: Option<Vec2>,
+    /// Last save / test play result
+    message: String,
+}
+
+impl Default for EditorTools {
+    fn default() -> Self {
+        Self {
+            tool: Tool::Wall,
+            snap: true,
+            memory_from: Difficulty::Easy,
+            drag_start: None,
+            message: String::new(),
+        }
+    }
+}
+
+impl EditorTools {
+    fn select(&mut self, tool: Tool) {
+        self.tool = tool;
+        self.drag_start = None;
+    }
+}
+
+impl Plugin for EditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EditorTools>()
+            .add_systems(OnEnter(GameState::Editor), enter_editor)
+            .add_systems(
+                Update,
+                (
+                    editor_keys,
+                    editor_mouse,
+                    rebuild_preview,
+                    update_editor_status,
+                    draw_editor_gizmos,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Editor).and(resource_exists::<EditorLayout>)),
+            )
+            .add_systems(OnExit(GameState::Editor), cleanup_editor)
+            .add_systems(OnEnter(GameState::Menu), forget_edited_level);
+    }
+}
+
+fn enter_editor(
+    mut commands: Commands,
+    layout: Option<ResMut<EditorLayout>>,
+    level_assets: Res<LevelAssets>,
+    levels: Res<Assets<LevelData>>,
+    mut tools: ResMut<EditorTools>,
+    bindings: Res<InputBindings>,
+) {
+    match layout {
+        // Coming back: rebuild the preview
+        Some(mut layout) => layout.set_changed(),
+        // First visit starts from the first campaign level
+        None => {
+            if let Some(level) = level_assets.campaign.first().and_then(|h| levels.get(h)) {
+                let mut level = level.clone();
+                level.name = "Edited".to_string();
+                commands.insert_resource(EditorLayout(level));
+            }
+        }
+    }
+    tools.drag_start = None;
+
+    commands.spawn((
+        EditorEntity,
+        EditorStatusText,
+        Text::new(""),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(8.0),
+            left: Val::Px(12.0),
+            ..default()
+        },
+    ));
+
+    let key = |c| bindings.primary_label(c);
+    commands.spawn((
+        EditorEntity,
+        Text::new(format!(
+            "{} wall (drag)  {} memory  {} start  •  right click = erase  •  {} = memory difficulty  •  {} = snap\n\
+             {}/{}/{}/{} = arena size  •  {} = save  •  {} = test play  •  {} = menu",
+            key(GameControl::EditorWallTool),
+            key(GameControl::EditorMemoryTool),
+            key(GameControl::EditorStartTool),
+            key(GameControl::EditorMemoryDifficulty),
+            key(GameControl::EditorSnap),
+            key(GameControl::EditorNarrower),
+            key(GameControl::EditorWider),
+            key(GameControl::EditorShorter),
+            key(GameControl::EditorTaller),
+            key(GameControl::EditorSave),
+            key(GameControl::EditorTestPlay),
+            key(GameControl::Cancel),
+        )),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.70, 0.70, 0.74)),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(8.0),
+            left: Val::Px(12.0),
+            ..default()
+        },
+    ));
+}
+
+fn editor_keys(
+    actions: Res<Actions>,
+    mut commands: Commands,
+    mut layout: ResMut<EditorLayout>,
+    mut tools: ResMut<EditorTools>,
+    mut config: ResMut<GameConfig>,
+    mut levels: ResMut<Assets<LevelData>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if actions.cancel {
+        next_state.set(GameState::Menu);
+        return;
+    }
+
+    let Some(intent) = actions.editor else {
+        return;
+    };
+    match intent {
+        EditorIntent::WallTool => tools.select(Tool::Wall),
+        EditorIntent::MemoryTool => tools.select(Tool::Memory),
+        EditorIntent::StartTool => tools.select(Tool::Start),
+        EditorIntent::ToggleSnap => tools.snap = !tools.snap,
+        EditorIntent::CycleDifficulty => {
+            tools.memory_from = match tools.memory_from {
+                Difficulty::Easy => Difficulty::Normal,
+                Difficulty::Normal => Difficulty::Hard,
+                Difficulty::Hard => Difficulty::Easy,
+            };
+        }
+        // Arena size, one grid step per press
+        EditorIntent::Resize(steps) => {
+            let half = &mut layout.0.half_extents;
+            *half = (*half + steps * GRID).clamp(MIN_HALF_EXTENTS, MAX_HALF_EXTENTS);
+        }
+        EditorIntent::Save => tools.message = save_layout(&layout.0),
+        EditorIntent::TestPlay => match level_validation::validate(&layout.0) {
+            Ok(()) => {
+                commands.insert_resource(CurrentLevel {
+                    handle: levels.add(layout.0.clone()),
+                    seed: None,
+                });
+                config.maze = MazeSource::Edited;
+                next_state.set(GameState::Countdown);
+            }
+            Err(errors) => {
+                tools.message = format!("Can't test play: {}", errors[0]);
+            }
+        },
+    }
+}
+
+fn save_layout(level: &LevelData) -> String {
+    let text = match ron::ser::to_string_pretty(level, ron::ser::PrettyConfig::default()) {
+        Ok(text) => text,
+        Err(e) => return format!("Save failed: {e}"),
+    };
+    if let Err(e) = std::fs::write(Path::new(SAVE_PATH), text) {
+        return format!("Save failed: {e}");
+    }
+    // The loader rejects broken layouts, so say so right away
+    match level_validation::validate(level) {
+        Ok(()) => format!("Saved to {SAVE_PATH}"),
+        Err(errors) => format!("Saved to {SAVE_PATH}, but it will not load: {}", errors[0]),
+    }
+}
+
+fn cursor_world(
+    q_window: &Query<&Window, With<PrimaryWindow>>,
+    q_camera: &Query<(&Camera, &GlobalTransform)>,
+) -> Option<Vec2> {
+    let screen = q_window.single().ok()?.cursor_position()?;
+    let (camera, cam_transform) = q_camera.single().ok()?;
+    camera.viewport_to_world_2d(cam_transform, screen).ok()
+}
+
+fn snap(p: Vec2, tools: &EditorTools) -> Vec2 {
+    if tools.snap {
+        (p / GRID).round() * GRID
+    } else {
+        p
+    }
+}
+
+fn editor_mouse(
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut layout: ResMut<EditorLayout>,
+    mut tools: ResMut<EditorTools>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_camera: Query<(&Camera, &GlobalTransform)>,
+) {
+    let Some(cursor) = cursor_world(&q_window, &q_camera) else {
+        return;
+    };
+    let pos = snap(cursor, &tools);
+
+    if mouse.just_pressed(MouseButton::Right) {
+        erase_at(&mut layout.0, cursor);
+        return;
+    }
+
+    match tools.tool {
+        Tool::Wall => {
+            if mouse.just_pressed(MouseButton::Left) {
+                tools.drag_start = Some(pos);
+            }
+            if mouse.just_released(MouseButton::Left)
+                && let Some(start) = tools.drag_start.take()
+            {
+                let rect = Rect::from_corners(start, pos);
+                if rect.width() >= MIN_WALL && rect.height() >= MIN_WALL {
+                    layout.0.walls.push(WallData {
+                        center: rect.center(),
+                        size: rect.size(),
+                    });
+                }
+            }
+        }
+        Tool::Memory => {
+            if mouse.just_pressed(MouseButton::Left) {
+                layout.0.memories.push(MemorySpawn {
+                    pos,
+                    from: tools.memory_from,
+                });
+            }
+        }
+        Tool::Start => {
+            if mouse.just_pressed(MouseButton::Left) {
+                layout.0.player_start = pos;
+            }
+        }
+    }
+}
+
+/// Remove the memory under `p`, or else the top-most wall under it
+fn erase_at(level: &mut LevelData, p: Vec2) {
+    if let Some(i) = level
+        .memories
+        .iter()
+        .rposition(|m| m.pos.distance(p) <= MEMORY_SIZE)
+    {
+        level.memories.remove(i);
+    } else if let Some(i) = level
+        .walls
+        .iter()
+        .rposition(|w| Rect::from_center_size(w.center, w.size).contains(p))
+    {
+        level.walls.remove(i);
+    }
+}
+
+/// Respawn the preview whenever the layout changes
+fn rebuild_preview(
+    mut commands: Commands,
+    layout: Res<EditorLayout>,
+    q_preview: Query<Entity, (With<EditorEntity>, Without<Node>)>,
+) {
+    if !layout.is_changed() {
+        return;
+    }
+
+    for e in &q_preview {
+        commands.entity(e).despawn();
+    }
+
+    let level = &layout.0;
+    spawn_level(&mut commands, level, EditorEntity);
+    for m in &level.memories {
+        spawn_memory(&mut commands, m.pos, EditorEntity);
+    }
+    commands.spawn((
+        EditorEntity,
+        Sprite {
+            color: Color::srgba(0.95, 0.2, 0.6, 0.6),
+            custom_size: Some(Vec2::splat(PLAYER_SIZE)),
+            ..default()
+        },
+        Transform::from_xyz(level.player_start.x, level.player_start.y, 1.0),
+    ));
+}
+
+fn update_editor_status(
+    layout: Res<EditorLayout>,
+    tools: Res<EditorTools>,
+    mut q_text: Query<&mut Text, With<EditorStatusText>>,
+) {
+    if !layout.is_changed() && !tools.is_changed() {
+        return;
+    }
+
+    let level = &layout.0;
+    let check = match level_validation::validate(level) {
+        Ok(()) => "layout OK".to_string(),
+        Err(errors) => format!("{} problem(s): {}", errors.len(), errors[0]),
+    };
+    let status = format!(
+        "EDITOR  •  tool: {}  •  memories from: {}  •  snap: {}  •  arena: {}x{}\n{check}\n{}",
+        tools.tool.label(),
+        tools.memory_from.label(),
+        if tools.snap { "on" } else { "off" },
+        level.half_extents.x * 2.0,
+        level.half_extents.y * 2.0,
+        tools.message,
+    );
+    for mut text in &mut q_text {
+        *text = Text::new(status.clone());
+    }
+}
+
+fn draw_editor_gizmos(
+    layout: Res<EditorLayout>,
+    tools: Res<EditorTools>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_camera: Query<(&Camera, &GlobalTransform)>,
+    mut gizmos: Gizmos,
+) {
+    let level = &layout.0;
+    if tools.snap {
+        let cells = (level.half_extents * 2.0 / GRID).as_uvec2();
+        gizmos.grid_2d(
+            Isometry2d::IDENTITY,
+            cells,
+            Vec2::splat(GRID),
+            Color::srgba(1.0, 1.0, 1.0, 0.05),
+        );
+    }
+
+    // Memories that only show up on harder difficulties get a coloured ring
+    for m in &level.memories {
+        let ring = match m.from {
+            Difficulty::Easy => continue,
+            Difficulty::Normal => Color::srgb(0.20, 0.90, 0.95),
+            Difficulty::Hard => Color::srgb(0.95, 0.35, 0.45),
+        };
+        gizmos.circle_2d(Isometry2d::from_translation(m.pos), MEMORY_SIZE, ring);
+    }
+
+    if let Some(start) = tools.drag_start
+        && let Some(cursor) = cursor_world(&q_window, &q_camera)
+    {
+        let rect = Rect::from_corners(start, snap(cursor, &tools));
+        gizmos.rect_2d(
+            Isometry2d::from_translation(rect.center()),
+            rect.size(),
+            Color::srgb(0.85, 0.25, 0.95),
+        );
+    }
+}
+
+fn cleanup_editor(mut commands: Commands, q: Query<Entity, With<EditorEntity>>) {
+    for e in &q {
+        commands.entity(e).despawn();
+    }
+}
+
+/// A test play ends in the menu; go back to the normal maze choices there
+fn forget_edited_level(mut config: ResMut<GameConfig>) {
+    if config.maze == MazeSource::Edited {
+        config.maze = MazeSource::Campaign;
+    }
+}
@@ -0,0 +1,388 @@
+//! Fever spikes: involuntary mood shifts on a seeded schedule.
+//!
+//! With `GameConfig::fever_spikes` on, a fever meter rises over the run and
+//! every so often the fever forces the player into another mood. Each spike is
+//! telegraphed first: the HUD flashes the mood that is coming and a ring around
+//! the player counts down to it. The schedule is rolled from `GameConfig::seed`,
+//! so a replay of the same run gets the same spikes.
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::audio::SoundEffect;
+use crate::level::{CurrentLevel, LevelData};
+use crate::mood::{Mood, Moods};
+use crate::player::{Player, RunStats, mood_input};
+use crate::{Difficulty, GameConfig, GameState, PauseState};
+
+pub struct FeverPlugin;
+
+/// Warning before a spike lands, seconds
+const TELEGRAPH_SECS: f32 = 1.5;
+/// Fever added by every spike, on top of the steady rise
+const SPIKE_HEAT: f32 = 0.05;
+/// At full fever the gaps between spikes shrink to this fraction
+const HOT_GAP_SCALE: f32 = 0.5;
+/// Random spread of every gap (± this fraction)
+const GAP_JITTER: f32 = 0.3;
+/// Radius of the countdown ring around the player
+const RING_RADIUS: f32 = 38.0;
+
+/// Average seconds between spikes at zero fever
+fn spike_gap(difficulty: Difficulty) -> f32 {
+    match difficulty {
+        Difficulty::Easy => 15.0,
+        Difficulty::Normal => 10.0,
+        Difficulty::Hard => 7.0,
+    }
+}
+
+/// Fever state of the current run. Only present while a run with fever spikes is playing.
+#[derive(Resource, Debug)]
+pub struct Fever {
+    /// 0..1; rises over the time limit and with every spike
+    pub level: f32,
+    /// Fever per second, so the meter tops out as the clock runs out
+    rise: f32,
+    base_gap: f32,
+    /// Seconds until the next spike lands
+    until_spike: f32,
+    /// Mood the next spike lands on, picked when the telegraph starts
+    incoming: Option<Mood>,
+    rng: StdRng,
+}
+
+impl Fever {
+    fn new(seed: u32, difficulty: Difficulty, time_limit: f32) -> Self {
+        let mut fever = Self {
+            level: 0.0,
+            rise: 1.0 / time_limit.max(1.0),
+            base_gap: spike_gap(difficulty),
+            until_spike: 0.0,
+            incoming: None,
+            // Kept apart from the maze generator's stream of the same seed
+            rng: StdRng::seed_from_u64(u64::from(seed) ^ 0xF3_7E_12),
+        };
+        fever.until_spike = fever.roll_gap();
+        fever
+    }
+
+    /// Next gap: shorter the hotter the fever, never shorter than the telegraph
+    fn roll_gap(&mut self) -> f32 {
+        let heat = 1.0 - (1.0 - HOT_GAP_SCALE) * self.level;
+        let jitter = self.rng.random_range(-GAP_JITTER..=GAP_JITTER);
+        (self.base_gap * heat * (1.0 + jitter)).max(TELEGRAPH_SECS + 0.5)
+    }
+
+    /// Seconds left on a running telegraph
+    fn telegraph_left(&self) -> Option<(Mood, f32)> {
+        self.incoming.map(|mood| (mood, self.until_spike.max(0.0)))
+    }
+}
+
+#[derive(Component)]
+struct FeverEntity;
+
+#[derive(Component)]
+struct FeverFill;
+
+#[derive(Component)]
+struct SpikeBanner;
+
+impl Plugin for FeverPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Playing), start_fever)
+            .add_systems(
+                Update,
+                (tick_fever.after(mood_input), update_fever_hud)
+                    .chain()
+                    .run_if(
+                        in_state(GameState::Playing)
+                            .and(in_state(PauseState::Running))
+                            .and(resource_exists::<Fever>),
+                    ),
+            )
+            .add_systems(
+                Update,
+                draw_spike_ring.run_if(in_state(GameState::Playing).and(resource_exists::<Fever>)),
+            )
+            .add_systems(OnEnter(GameState::Countdown), cleanup_fever)
+            .add_systems(OnExit(GameState::GameOver), cleanup_fever)
+            .add_systems(OnEnter(GameState::Menu), cleanup_fever);
+    }
+}
+
+fn start_fever(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    current_level: Res<CurrentLevel>,
+    levels: Res<Assets<LevelData>>,
+) {
+    if !config.fever_spikes {
+        return;
+    }
+    let time_limit = levels
+        .get(&current_level.handle)
+        .map_or(60.0, |l| l.time_limit.get(config.difficulty));
+    commands.insert_resource(Fever::new(config.seed, config.difficulty, time_limit));
+
+    // Meter under the HUD bar (top-right), spike warning under it
+    commands
+        .spawn((
+            FeverEntity,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(76.0),
+                right: Val::Px(18.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::FlexEnd,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+        ))
+        .with_children(|col| {
+            col.spawn((
+                FeverEntity,
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(10.0),
+                    ..default()
+                },
+            ))
+            .with_children(|row| {
+                row.spawn((
+                    FeverEntity,
+                    Text::new("FEVER"),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(1.0, 0.55, 0.35)),
+                ));
+                row.spawn((
+                    FeverEntity,
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(12.0),
+                        border: UiRect::all(Val::Px(1.0)),
+                        border_radius: BorderRadius::all(Val::Px(6.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.14, 0.14, 0.16)),
+                    BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
+                ))
+                .with_child((
+                    FeverEntity,
+                    FeverFill,
+                    Node {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        border_radius: BorderRadius::all(Val::Px(6.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(1.0, 0.8, 0.3)),
+                ));
+            });
+
+            col.spawn((
+                FeverEntity,
+                SpikeBanner,
+                Text::new(""),
+                TextFont {
+                    font_size: 30.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+/// Steady rise, then telegraph and land the next spike
+fn tick_fever(
+    time: Res<Time>,
+    moods: Moods,
+    mut fever: ResMut<Fever>,
+    mut mood: ResMut<Mood>,
+    mut stats: ResMut<RunStats>,
+    mut sfx: MessageWriter<SoundEffect>,
+) {
+    let count = moods.all().len();
+    // Nothing to shift between
+    if count < 2 {
+        return;
+    }
+
+    let dt = time.delta_secs();
+    fever.level = (fever.level + fever.rise * dt).min(1.0);
+    fever.until_spike -= dt;
+
+    if fever.incoming.is_none() && fever.until_spike <= TELEGRAPH_SECS {
+        // Any mood but the current one
+        let pick = fever.rng.random_range(1..count);
+        fever.incoming = Some(Mood((mood.0 + pick) % count));
+        sfx.write(SoundEffect::CountdownTick);
+    }
+
+    if fever.until_spike <= 0.0
+        && let Some(target) = fever.incoming.take()
+    {
+        *mood = target;
+        stats.fever_spikes += 1;
+        fever.level = (fever.level + SPIKE_HEAT).min(1.0);
+        fever.until_spike = fever.roll_gap();
+        sfx.write(SoundEffect::CountdownGo);
+    }
+}
+
+fn update_fever_hud(
+    time: Res<Time>,
+    fever: Res<Fever>,
+    moods: Moods,
+    mut q_fill: Query<(&mut Node, &mut BackgroundColor), With<FeverFill>>,
+    mut q_banner: Query<(&mut Text, &mut TextColor), With<SpikeBanner>>,
+) {
+    let calm = Color::srgb(1.0, 0.8, 0.3);
+    let hot = Color::srgb(1.0, 0.25, 0.3);
+    for (mut node, mut bg) in &mut q_fill {
+        node.width = Val::Percent(fever.level * 100.0);
+        bg.0 = calm.mix(&hot, fever.level);
+    }
+
+    let (text, color) = match fever.telegraph_left() {
+        Some((target, left)) => {
+            // Flashes faster as the spike gets closer
+            let rate = 4.0 + 8.0 * (1.0 - left / TELEGRAPH_SECS);
+            let on = (time.elapsed_secs() * rate).sin() > -0.3;
+            let color = moods.get(target).map_or(Color::WHITE, |m| m.color());
+            (
+                format!("FEVER SPIKE → {}", moods.name(target)),
+                color.with_alpha(if on { 1.0 } else { 0.25 }),
+            )
+        }
+        None => (String::new(), Color::WHITE),
+    };
+    for (mut t, mut c) in &mut q_banner {
+        if t.0 != text {
+            t.0 = text.clone();
+        }
+        c.0 = color;
+    }
+}
+
+/// Ring around the player that runs out as the spike lands, in the incoming mood's colour
+fn draw_spike_ring(
+    fever: Res<Fever>,
+    moods: Moods,
+    q_player: Query<&Transform, With<Player>>,
+    mut gizmos: Gizmos,
+) {
+    let Some((target, left)) = fever.telegraph_left() else {
+        return;
+    };
+    let Ok(player) = q_player.single() else {
+        return;
+    };
+    let color = moods.get(target).map_or(Color::WHITE, |m| m.color());
+    let at = Isometry2d::from_translation(player.translation.truncate());
+
+    gizmos.circle_2d(at, RING_RADIUS, color.with_alpha(0.2));
+    let share = (left / TELEGRAPH_SECS).clamp(0.0, 1.0);
+    if share > 0.0 {
+        gizmos
+            .arc_2d(at, share * std::f32::consts::TAU, RING_RADIUS, color)
+            .resolution(48);
+    }
+}
+
+fn cleanup_fever(mut commands: Commands, q: Query<Entity, With<FeverEntity>>) {
+    for e in &q {
+        commands.entity(e).despawn();
+    }
+    commands.remove_resource::<Fever>();
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+    use crate::level::{add_seed_rolls, reroll_seed_with};
+
+    fn gaps(fever: &mut Fever) -> Vec<f32> {
+        (0..8).map(|_| fever.roll_gap()).collect()
+    }
+
+    #[test]
+    fn schedule_follows_the_seed_and_speeds_up_with_fever() {
+        let mut a = Fever::new(7, Difficulty::Normal, 60.0);
+        let mut b = Fever::new(7, Difficulty::Normal, 60.0);
+        assert_eq!(a.until_spike, b.until_spike);
+        assert_eq!(gaps(&mut a), gaps(&mut b));
+
+        let mut cold = Fever::new(7, Difficulty::Hard, 60.0);
+        let mut hot = Fever::new(7, Difficulty::Hard, 60.0);
+        hot.level = 1.0;
+        for (c, h) in gaps(&mut cold).into_iter().zip(gaps(&mut hot)) {
+            assert!(h < c, "{h} at full fever vs {c} cold");
+            assert!(h > TELEGRAPH_SECS);
+        }
+    }
+
+    /// Step through `path` (one state per frame) and return the seed the run started with
+    fn start_run(app: &mut App, path: &[GameState]) -> u32 {
+        for state in path {
+            app.world_mut()
+                .resource_mut::<NextState<GameState>>()
+                .set(state.clone());
+            app.update();
+        }
+        app.world().resource::<GameConfig>().seed
+    }
+
+    #[test]
+    fn every_campaign_start_gets_a_new_seed() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .init_resource::<GameConfig>();
+        add_seed_rolls(&mut app);
+
+        let campaign = [
+            GameState::Menu,
+            GameState::LevelSelect,
+            GameState::Countdown,
+        ];
+        let first = start_run(&mut app, &campaign);
+        // Retry keeps the schedule
+        let retry = start_run(&mut app, &[GameState::GameOver, GameState::Countdown]);
+        assert_eq!(retry, first);
+        let second = start_run(&mut app, &campaign);
+        assert_ne!(second, first);
+    }
+
+    #[test]
+    fn reroll_always_changes_the_seed() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut config = GameConfig::default();
+        for _ in 0..100 {
+            let before = config.seed;
+            reroll_seed_with(&mut config, &mut rng);
+            assert_ne!(config.seed, before);
+        }
+    }
+
+    #[test]
+    fn different_seeds_give_different_spikes() {
+        let schedule = |seed| {
+            let mut fever = Fever::new(seed, Difficulty::Normal, 60.0);
+            let first_gap = fever.until_spike;
+            let mut all = gaps(&mut fever);
+            all.insert(0, first_gap);
+            all
+        };
+        assert_eq!(schedule(1), schedule(1));
+        assert_ne!(schedule(1), schedule(2));
+    }
+}
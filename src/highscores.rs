@@ -0,0 +1,230 @@
+//! Local high-score tables, one per level ("board") and difficulty.
+//!
+//! Every finished run with points is submitted to the table for its board;
+//! the tables are persisted under the `highscores` key.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::level::{CurrentLevel, LevelData};
+use crate::player::RunOutcome;
+use crate::storage;
+use crate::{Difficulty, GameConfig, MazeSource};
+
+pub struct HighScoresPlugin;
+
+const HIGHSCORES_KEY: &str = "highscores";
+/// Entries kept per table
+pub const TABLE_SIZE: usize = 10;
+/// All random mazes share one board, whatever the seed
+const RANDOM_BOARD: &str = "Random maze";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct HighScoreEntry {
+    pub name: String,
+    pub score: u32,
+    /// Seconds to collect every memory, `None` when time ran out
+    pub clear_time: Option<f32>,
+    /// Unix time (seconds) the run ended
+    pub date: u64,
+}
+
+impl HighScoreEntry {
+    /// Higher score first, a faster clear breaks ties
+    fn beats(&self, other: &HighScoreEntry) -> bool {
+        let time = |e: &HighScoreEntry| e.clear_time.unwrap_or(f32::INFINITY);
+        self.score > other.score || (self.score == other.score && time(self) < time(other))
+    }
+}
+
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct HighScores {
+    /// Best first, at most `TABLE_SIZE` entries each
+    tables: HashMap<String, Vec<HighScoreEntry>>,
+}
+
+impl HighScores {
+    fn key(board: &str, difficulty: Difficulty) -> String {
+        format!("{board}/{}", difficulty.label())
+    }
+
+    pub fn table(&self, board: &str, difficulty: Difficulty) -> &[HighScoreEntry] {
+        self.tables
+            .get(&Self::key(board, difficulty))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Add a run; returns its rank (0 = new best) if it made the table
+    fn submit(
+        &mut self,
+        board: &str,
+        difficulty: Difficulty,
+        entry: HighScoreEntry,
+    ) -> Option<usize> {
+        let table = self.tables.entry(Self::key(board, difficulty)).or_default();
+        // Equal runs keep the older entry in front
+        let rank = table
+            .iter()
+            .position(|e| entry.beats(e))
+            .unwrap_or(table.len());
+        if rank >= TABLE_SIZE {
+            return None;
+        }
+        table.insert(rank, entry);
+        table.truncate(TABLE_SIZE);
+        Some(rank)
+    }
+}
+
+/// Where the last run landed in its table. Only present when it made the table.
+#[derive(Resource, Debug, Clone)]
+pub struct RunRank {
+    pub board: String,
+    pub rank: usize,
+}
+
+impl Plugin for HighScoresPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(storage::load::<HighScores>(HIGHSCORES_KEY).unwrap_or_default());
+    }
+}
+
+/// Table a run belongs to; edited levels don't get one
+pub(crate) fn board_name(maze: MazeSource, level: Option<&LevelData>) -> Option<String> {
+    match maze {
+        MazeSource::Campaign => level.map(|l| l.name.clone()),
+        MazeSource::Generated => Some(RANDOM_BOARD.to_string()),
+        #[cfg(feature = "dev")]
+        MazeSource::Edited => None,
+    }
+}
+
+/// Submits the run that just ended. Runs before the Game Over card is built.
+pub(crate) fn record_high_score(
+    mut commands: Commands,
+    outcome: Res<RunOutcome>,
+    config: Res<GameConfig>,
+    current_level: Res<CurrentLevel>,
+    levels: Res<Assets<LevelData>>,
+    mut scores: ResMut<HighScores>,
+) {
+    commands.remove_resource::<RunRank>();
+
+    let board = board_name(config.maze, levels.get(&current_level.handle));
+    let Some(board) = board.filter(|_| outcome.score > 0) else {
+        return;
+    };
+
+    let name = config.player_name.trim();
+    let entry = HighScoreEntry {
+        name: if name.is_empty() { "Player" } else { name }.to_string(),
+        score: outcome.score,
+        clear_time: outcome.is_victory().then_some(outcome.time),
+        date: now_unix(),
+    };
+
+    if let Some(rank) = scores.submit(&board, config.difficulty, entry) {
+        storage::save(HIGHSCORES_KEY, &*scores);
+        commands.insert_resource(RunRank { board, rank });
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn now_unix() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+// `SystemTime::now` panics on the web
+#[cfg(target_arch = "wasm32")]
+fn now_unix() -> u64 {
+    (js_sys::Date::now() / 1000.0) as u64
+}
+
+/// `YYYY-MM-DD` (UTC) for a unix time
+pub fn format_date(unix: u64) -> String {
+    // Days since 1970-01-01 to a civil date (Howard Hinnant's algorithm)
+    let z = (unix / 86_400) as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(name: &str, score: u32, clear_time: Option<f32>) -> HighScoreEntry {
+        HighScoreEntry {
+            name: name.to_string(),
+            score,
+            clear_time,
+            date: 0,
+        }
+    }
+
+    #[test]
+    fn equal_run_ranks_behind_the_older_one() {
+        let mut scores = HighScores::default();
+        let normal = Difficulty::Normal;
+        assert_eq!(
+            scores.submit("dream", normal, run("old", 500, Some(30.0))),
+            Some(0)
+        );
+        assert_eq!(
+            scores.submit("dream", normal, run("new", 500, Some(30.0))),
+            Some(1)
+        );
+        // A faster clear on the same score still wins
+        assert_eq!(
+            scores.submit("dream", normal, run("fast", 500, Some(20.0))),
+            Some(0)
+        );
+
+        let names: Vec<&str> = scores
+            .table("dream", normal)
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect();
+        assert_eq!(names, ["fast", "old", "new"]);
+    }
+
+    #[test]
+    fn full_table_rejects_a_worse_run() {
+        let mut scores = HighScores::default();
+        for i in 0..TABLE_SIZE as u32 {
+            assert!(
+                scores
+                    .submit("dream", Difficulty::Hard, run("p", 100 + i, None))
+                    .is_some()
+            );
+        }
+        assert_eq!(
+            scores.submit("dream", Difficulty::Hard, run("late", 100, None)),
+            None
+        );
+        assert_eq!(scores.table("dream", Difficulty::Hard).len(), TABLE_SIZE);
+        // Other difficulties have tables of their own
+        assert_eq!(
+            scores.submit("dream", Difficulty::Easy, run("late", 100, None)),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+        assert_eq!(format_date(1_735_689_599), "2024-12-31");
+    }
+}
@@ -0,0 +1,229 @@
+//! Level files: arena size, walls, memory spawns, player start and time limits.
+//!
+//! Levels live in `assets/levels/*.level.ron` and are read by [`LevelLoader`],
+//! so new layouts only need a new file (and an entry in `LevelAssets::campaign`).
+//! Layouts that fail `level_validation` are rejected at load time.
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::level_validation::{self, LevelError};
+use crate::loading::LevelAssets;
+use crate::maze_gen;
+use crate::pathfinding::NavGrid;
+use crate::player::PLAYER_HALF_EXTENTS;
+use crate::{Difficulty, GameConfig, GameState, MazeSource};
+
+pub struct LevelPlugin;
+
+impl Plugin for LevelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<LevelData>()
+            .init_asset_loader::<LevelLoader>();
+        add_seed_rolls(app);
+    }
+}
+
+/// Roll a new seed whenever a fresh run starts: from the menu, the level select or
+/// the editor. Retry and Restart come from Game Over / the run itself and keep it.
+pub(crate) fn add_seed_rolls(app: &mut App) {
+    let fresh_starts = [
+        GameState::Menu,
+        GameState::LevelSelect,
+        #[cfg(feature = "dev")]
+        GameState::Editor,
+    ];
+    for exited in fresh_starts {
+        app.add_systems(
+            OnTransition {
+                exited,
+                entered: GameState::Countdown,
+            },
+            roll_seed,
+        );
+    }
+}
+
+/// Arena size used for generated mazes
+pub const GENERATED_HALF_EXTENTS: Vec2 = Vec2::new(520.0, 300.0);
+/// Extra room around the player hitbox when checking what it can reach (validation and click-to-move routing)
+pub const NAV_CLEARANCE: f32 = 2.0;
+
+/// The level this run is played on: a level file or a generated maze
+#[derive(Resource)]
+pub struct CurrentLevel {
+    pub handle: Handle<LevelData>,
+    /// Set for generated mazes
+    pub seed: Option<u32>,
+}
+
+#[derive(Asset, TypePath, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LevelData {
+    pub name: String,
+    /// Half width / half height of the arena, centred on the origin
+    pub half_extents: Vec2,
+    pub player_start: Vec2,
+    pub time_limit: TimeLimits,
+    pub walls: Vec<WallData>,
+    pub memories: Vec<MemorySpawn>,
+}
+
+/// Seconds per run, by difficulty
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct TimeLimits {
+    pub easy: f32,
+    pub normal: f32,
+    pub hard: f32,
+}
+
+impl TimeLimits {
+    pub fn get(&self, difficulty: Difficulty) -> f32 {
+        match difficulty {
+            Difficulty::Easy => self.easy,
+            Difficulty::Normal => self.normal,
+            Difficulty::Hard => self.hard,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct WallData {
+    pub center: Vec2,
+    pub size: Vec2,
+}
+
+/// A memory that shows up from `from` difficulty upwards
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct MemorySpawn {
+    pub pos: Vec2,
+    #[serde(default = "easiest")]
+    pub from: Difficulty,
+}
+
+fn easiest() -> Difficulty {
+    Difficulty::Easy
+}
+
+impl LevelData {
+    /// Where the player collider fits inside the arena (see `pathfinding`)
+    pub fn nav_grid(&self, cell: f32) -> NavGrid {
+        let walls: Vec<Rect> = self
+            .walls
+            .iter()
+            .map(|w| Rect::from_center_size(w.center, w.size))
+            .collect();
+        NavGrid::build(
+            self.half_extents,
+            PLAYER_HALF_EXTENTS,
+            &walls,
+            PLAYER_HALF_EXTENTS + NAV_CLEARANCE,
+            cell,
+        )
+    }
+
+    pub fn memories_for(&self, difficulty: Difficulty) -> impl Iterator<Item = Vec2> + '_ {
+        self.memories
+            .iter()
+            .filter(move |m| m.from <= difficulty)
+            .map(|m| m.pos)
+    }
+}
+
+/// A fresh maze and fever schedule for a new run (replays keep the seed)
+fn roll_seed(mut config: ResMut<GameConfig>) {
+    reroll_seed(&mut config);
+}
+
+pub(crate) fn reroll_seed(config: &mut GameConfig) {
+    reroll_seed_with(config, &mut rand::rng());
+}
+
+/// Always lands on a different seed, so a fresh run never repeats the last one
+pub(crate) fn reroll_seed_with(config: &mut GameConfig, rng: &mut impl Rng) {
+    config.seed = config.seed.wrapping_add(rng.random_range(1..=u32::MAX));
+}
+
+/// Pick (or generate) the level for the run that is about to start
+pub(crate) fn select_level(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    level_assets: Res<LevelAssets>,
+    mut levels: ResMut<Assets<LevelData>>,
+) {
+    let current = match config.maze {
+        MazeSource::Campaign => CurrentLevel {
+            handle: level_assets.campaign[config.level.min(level_assets.campaign.len() - 1)]
+                .clone(),
+            seed: None,
+        },
+        MazeSource::Generated => {
+            let level = maze_gen::generate(config.seed, GENERATED_HALF_EXTENTS, config.difficulty);
+            CurrentLevel {
+                handle: levels.add(level),
+                seed: Some(config.seed),
+            }
+        }
+        // The editor already put its layout in `CurrentLevel`
+        #[cfg(feature = "dev")]
+        MazeSource::Edited => return,
+    };
+    commands.insert_resource(current);
+}
+
+#[derive(Default, TypePath)]
+pub struct LevelLoader;
+
+#[derive(Debug)]
+pub enum LevelLoadError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+    /// Parsed fine but the layout is broken (see `level_validation`)
+    Invalid(Vec<LevelError>),
+}
+
+impl std::fmt::Display for LevelLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LevelLoadError::Io(e) => write!(f, "could not read level file: {e}"),
+            LevelLoadError::Ron(e) => write!(f, "invalid level file: {e}"),
+            LevelLoadError::Invalid(errors) => {
+                write!(f, "broken level layout")?;
+                for e in errors {
+                    write!(f, "\n  - {e}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for LevelLoadError {}
+
+impl AssetLoader for LevelLoader {
+    type Asset = LevelData;
+    type Settings = ();
+    type Error = LevelLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<LevelData, LevelLoadError> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(LevelLoadError::Io)?;
+        let level: LevelData = ron::de::from_bytes(&bytes).map_err(LevelLoadError::Ron)?;
+        level_validation::validate(&level).map_err(LevelLoadError::Invalid)?;
+        Ok(level)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["level.ron"]
+    }
+}
@@ -0,0 +1,280 @@
+use bevy::prelude::*;
+
+use crate::actions::Actions;
+use crate::campaign::CampaignProgress;
+use crate::level::LevelData;
+use crate::loading::LevelAssets;
+use crate::{GameConfig, GameState};
+
+/// Campaign level list: locked / cleared / best time, pick one to play
+pub struct LevelSelectPlugin;
+
+#[derive(Component)]
+struct LevelSelectTag;
+
+#[derive(Component)]
+struct LevelButton(usize);
+
+#[derive(Component)]
+struct BackButton;
+
+impl Plugin for LevelSelectPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::LevelSelect), setup_level_select)
+            .add_systems(
+                Update,
+                (
+                    level_select_input,
+                    level_select_buttons,
+                    level_select_visuals,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::LevelSelect)),
+            )
+            .add_systems(OnExit(GameState::LevelSelect), cleanup_level_select);
+    }
+}
+
+/// Name of every campaign level, in order ("Level N" if one is somehow missing)
+fn level_names(level_assets: &LevelAssets, levels: &Assets<LevelData>) -> Vec<String> {
+    level_assets
+        .campaign
+        .iter()
+        .enumerate()
+        .map(|(i, h)| {
+            levels
+                .get(h)
+                .map(|l| l.name.clone())
+                .unwrap_or_else(|| format!("Level {}", i + 1))
+        })
+        .collect()
+}
+
+fn setup_level_select(
+    mut commands: Commands,
+    mut config: ResMut<GameConfig>,
+    progress: Res<CampaignProgress>,
+    level_assets: Res<LevelAssets>,
+    levels: Res<Assets<LevelData>>,
+) {
+    let names = level_names(&level_assets, &levels);
+
+    // Preselect where the player left off: the first level not cleared yet
+    if let Some(i) = names.iter().position(|n| !progress.is_cleared(n)) {
+        config.level = i;
+    } else {
+        config.level = config.level.min(names.len().saturating_sub(1));
+    }
+
+    commands
+        .spawn((
+            LevelSelectTag,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.05, 0.05, 0.06)),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                LevelSelectTag,
+                Node {
+                    width: Val::Px(620.0),
+                    padding: UiRect::all(Val::Px(24.0)),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(12.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    border_radius: BorderRadius::all(Val::Px(18.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.09, 0.09, 0.11)),
+                BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
+            ))
+            .with_children(|card| {
+                card.spawn((
+                    LevelSelectTag,
+                    Text::new("LEVELS"),
+                    TextFont {
+                        font_size: 40.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.95, 0.95, 0.98)),
+                ));
+
+                for (i, name) in names.iter().enumerate() {
+                    let previous = i.checked_sub(1).map(|p| names[p].as_str());
+                    let unlocked = progress.is_unlocked(previous);
+                    let status = match progress.best_time(name) {
+                        _ if !unlocked => "Locked".to_string(),
+                        Some(best) => format!("Cleared • best {best:.1}s"),
+                        None => "Not cleared".to_string(),
+                    };
+                    spawn_level_row(card, i, name, &status, unlocked);
+                }
+
+                card.spawn((
+                    LevelSelectTag,
+                    Button,
+                    BackButton,
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(52.0),
+                        margin: UiRect::top(Val::Px(10.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(1.0)),
+                        border_radius: BorderRadius::all(Val::Px(14.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.14, 0.14, 0.16)),
+                    BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
+                ))
+                .with_child((
+                    LevelSelectTag,
+                    Text::new("BACK"),
+                    TextFont {
+                        font_size: 22.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            });
+        });
+}
+
+fn spawn_level_row(
+    card: &mut ChildSpawnerCommands,
+    index: usize,
+    name: &str,
+    status: &str,
+    unlocked: bool,
+) {
+    let text_color = if unlocked {
+        Color::WHITE
+    } else {
+        Color::srgb(0.45, 0.45, 0.50)
+    };
+
+    let mut row = card.spawn((
+        LevelSelectTag,
+        Node {
+            height: Val::Px(52.0),
+            padding: UiRect::horizontal(Val::Px(16.0)),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(1.0)),
+            border_radius: BorderRadius::all(Val::Px(14.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.13, 0.13, 0.15)),
+        BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
+    ));
+    // Locked levels are shown but can't be picked
+    if unlocked {
+        row.insert((Button, LevelButton(index)));
+    }
+
+    row.with_children(|row| {
+        row.spawn((
+            LevelSelectTag,
+            Text::new(format!("{}. {name}", index + 1)),
+            TextFont {
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(text_color),
+        ));
+        row.spawn((
+            LevelSelectTag,
+            Text::new(status),
+            TextFont {
+                font_size: 16.0,
+                ..default()
+            },
+            TextColor(if unlocked {
+                Color::srgb(0.35, 0.9, 0.95)
+            } else {
+                text_color
+            }),
+        ));
+    });
+}
+
+fn level_select_input(actions: Res<Actions>, mut next_state: ResMut<NextState<GameState>>) {
+    if actions.confirm {
+        // Plays the preselected level
+        next_state.set(GameState::Countdown);
+    } else if actions.cancel {
+        next_state.set(GameState::Menu);
+    }
+}
+
+fn level_select_buttons(
+    q: Query<
+        (&Interaction, Option<&LevelButton>, Option<&BackButton>),
+        (With<Button>, Changed<Interaction>),
+    >,
+    mut config: ResMut<GameConfig>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for (i, level, back) in &q {
+        if *i != Interaction::Pressed {
+            continue;
+        }
+
+        if let Some(level) = level {
+            config.level = level.0;
+            next_state.set(GameState::Countdown);
+        } else if back.is_some() {
+            next_state.set(GameState::Menu);
+        }
+    }
+}
+
+fn level_select_visuals(
+    config: Res<GameConfig>,
+    mut q: Query<
+        (
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            Option<&LevelButton>,
+        ),
+        (With<Button>, With<LevelSelectTag>),
+    >,
+) {
+    let dim_border = Color::srgb(0.22, 0.22, 0.28);
+    let cyan = Color::srgb(0.20, 0.90, 0.95);
+    let purple = Color::srgb(0.85, 0.25, 0.95);
+
+    for (i, mut bg, mut border, level) in &mut q {
+        match *i {
+            Interaction::Pressed => {
+                bg.0 = Color::srgb(0.24, 0.24, 0.30);
+                *border = BorderColor::all(purple);
+            }
+            Interaction::Hovered => {
+                bg.0 = Color::srgb(0.20, 0.20, 0.24);
+                *border = BorderColor::all(cyan);
+            }
+            // The level Enter would start
+            Interaction::None if level.is_some_and(|l| l.0 == config.level) => {
+                bg.0 = Color::srgb(0.16, 0.16, 0.20);
+                *border = BorderColor::all(cyan);
+            }
+            Interaction::None => {
+                bg.0 = Color::srgb(0.14, 0.14, 0.16);
+                *border = BorderColor::all(dim_border);
+            }
+        }
+    }
+}
+
+fn cleanup_level_select(mut commands: Commands, q: Query<Entity, With<LevelSelectTag>>) {
+    for e in &q {
+        commands.entity(e).despawn();
+    }
+}
@@ -0,0 +1,275 @@
+//! Sanity checks for level layouts.
+//!
+//! A layout is rejected when the player cannot stand at the start, when some
+//! difficulty has no memories to collect (the run would be won on its first
+//! frame), or when a memory sits in a wall, outside the arena or somewhere the player cannot
+//! get close enough to pick it up (walls, the arena border or a sealed pocket).
+
+use bevy::prelude::*;
+
+use crate::Difficulty;
+use crate::level::LevelData;
+use crate::player::{MEMORY_RADIUS, PLAYER_HALF_EXTENTS};
+
+/// Grid resolution used for the reachability check
+const CHECK_CELL: f32 = 8.0;
+
+/// With its centre this close, the player collider surely touches a memory
+/// (the circle inscribed in the player box reaches the memory's circle)
+fn pickup_reach() -> f32 {
+    PLAYER_HALF_EXTENTS.min_element() + MEMORY_RADIUS
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LevelError {
+    /// The arena is not bigger than the player on some axis
+    ArenaTooSmall {
+        half_extents: Vec2,
+    },
+    TimeLimit {
+        difficulty: Difficulty,
+        seconds: f32,
+    },
+    /// Nothing to collect on this difficulty
+    NoMemories {
+        difficulty: Difficulty,
+    },
+    /// Player start is outside the area `clamp_player` allows (collider inside the arena)
+    StartOutsideArena {
+        pos: Vec2,
+    },
+    StartInWall {
+        pos: Vec2,
+        wall: usize,
+    },
+    MemoryOutsideArena {
+        memory: usize,
+        pos: Vec2,
+    },
+    MemoryInWall {
+        memory: usize,
+        pos: Vec2,
+        wall: usize,
+    },
+    /// The player can never get within pickup range
+    MemoryUnreachable {
+        memory: usize,
+        pos: Vec2,
+    },
+}
+
+impl std::fmt::Display for LevelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LevelError::ArenaTooSmall { half_extents } => write!(
+                f,
+                "arena half extents {half_extents} leave no room for the player collider"
+            ),
+            LevelError::TimeLimit {
+                difficulty,
+                seconds,
+            } => write!(
+                f,
+                "time limit for {} must be positive, got {seconds}",
+                difficulty.label()
+            ),
+            LevelError::NoMemories { difficulty } => {
+                write!(f, "no memories to collect on {}", difficulty.label())
+            }
+            LevelError::StartOutsideArena { pos } => {
+                write!(f, "player start {pos} is outside the playable area")
+            }
+            LevelError::StartInWall { pos, wall } => {
+                write!(f, "player start {pos} overlaps wall #{wall}")
+            }
+            LevelError::MemoryOutsideArena { memory, pos } => {
+                write!(f, "memory #{memory} at {pos} is outside the arena")
+            }
+            LevelError::MemoryInWall { memory, pos, wall } => {
+                write!(f, "memory #{memory} at {pos} overlaps wall #{wall}")
+            }
+            LevelError::MemoryUnreachable { memory, pos } => {
+                write!(
+                    f,
+                    "memory #{memory} at {pos} cannot be reached from the start"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for LevelError {}
+
+/// Check a layout; returns every problem found (indices are positions in the level file lists)
+pub fn validate(level: &LevelData) -> Result<(), Vec<LevelError>> {
+    let mut errors = Vec::new();
+
+    let playable = level.half_extents - PLAYER_HALF_EXTENTS;
+    if playable.x <= 0.0 || playable.y <= 0.0 {
+        return Err(vec![LevelError::ArenaTooSmall {
+            half_extents: level.half_extents,
+        }]);
+    }
+
+    for difficulty in [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard] {
+        let seconds = level.time_limit.get(difficulty);
+        if seconds.is_nan() || seconds <= 0.0 {
+            errors.push(LevelError::TimeLimit {
+                difficulty,
+                seconds,
+            });
+        }
+        if level.memories_for(difficulty).next().is_none() {
+            errors.push(LevelError::NoMemories { difficulty });
+        }
+    }
+
+    let walls: Vec<Rect> = level
+        .walls
+        .iter()
+        .map(|w| Rect::from_center_size(w.center, w.size))
+        .collect();
+    let first_overlap = |rect: Rect| walls.iter().position(|w| !w.intersect(rect).is_empty());
+
+    let start = level.player_start;
+    if start.abs().cmpgt(playable).any() {
+        errors.push(LevelError::StartOutsideArena { pos: start });
+    } else if let Some(wall) =
+        first_overlap(Rect::from_center_half_size(start, PLAYER_HALF_EXTENTS))
+    {
+        errors.push(LevelError::StartInWall { pos: start, wall });
+    }
+
+    let nav = level.nav_grid(CHECK_CELL);
+    // The nav grid keeps a little clearance, so start from the closest cell it allows
+    let reached = nav
+        .nearest_free(nav.cell_of(start))
+        .map(|c| nav.reachable_from(c))
+        .unwrap_or_default();
+
+    for (memory, spawn) in level.memories.iter().enumerate() {
+        let pos = spawn.pos;
+        let rect = Rect::from_center_half_size(pos, Vec2::splat(MEMORY_RADIUS));
+        if rect.min.cmplt(-level.half_extents).any() || rect.max.cmpgt(level.half_extents).any() {
+            errors.push(LevelError::MemoryOutsideArena { memory, pos });
+        } else if let Some(wall) = first_overlap(rect) {
+            errors.push(LevelError::MemoryInWall { memory, pos, wall });
+        } else if !nav.reachable_near(&reached, pos, pickup_reach()) {
+            errors.push(LevelError::MemoryUnreachable { memory, pos });
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::{GENERATED_HALF_EXTENTS, MemorySpawn, WallData};
+    use crate::maze_gen;
+
+    /// Every level file in the campaign
+    const BUILT_IN: [(&str, &str); 3] = [
+        ("dream", include_str!("../assets/levels/dream.level.ron")),
+        (
+            "corridors",
+            include_str!("../assets/levels/corridors.level.ron"),
+        ),
+        ("spiral", include_str!("../assets/levels/spiral.level.ron")),
+    ];
+
+    fn dream() -> LevelData {
+        ron::de::from_str(BUILT_IN[0].1).unwrap()
+    }
+
+    #[test]
+    fn built_in_levels_are_valid() {
+        for (file, text) in BUILT_IN {
+            let level: LevelData = ron::de::from_str(text).unwrap();
+            assert_eq!(validate(&level), Ok(()), "{file}.level.ron");
+        }
+    }
+
+    #[test]
+    fn generated_mazes_are_valid() {
+        for difficulty in [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard] {
+            for seed in 0..50 {
+                let level = maze_gen::generate(seed, GENERATED_HALF_EXTENTS, difficulty);
+                assert_eq!(validate(&level), Ok(()), "seed {seed} on {difficulty:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn memory_inside_wall_is_rejected() {
+        let mut level = dream();
+        let wall = level.walls[0].center;
+        level.memories.push(MemorySpawn {
+            pos: wall,
+            from: Difficulty::Easy,
+        });
+        let memory = level.memories.len() - 1;
+        assert_eq!(
+            validate(&level),
+            Err(vec![LevelError::MemoryInWall {
+                memory,
+                pos: wall,
+                wall: 0
+            }])
+        );
+    }
+
+    #[test]
+    fn sealed_pocket_is_rejected() {
+        let mut level = dream();
+        // Box in the top-right corner, no way in
+        let corner = level.half_extents - Vec2::splat(60.0);
+        for (center, size) in [
+            (corner + Vec2::new(-50.0, 0.0), Vec2::new(20.0, 140.0)),
+            (corner + Vec2::new(0.0, -50.0), Vec2::new(140.0, 20.0)),
+        ] {
+            level.walls.push(WallData { center, size });
+        }
+        level.memories = vec![MemorySpawn {
+            pos: corner + Vec2::splat(10.0),
+            from: Difficulty::Easy,
+        }];
+        let errors = validate(&level).unwrap_err();
+        assert!(matches!(
+            errors.as_slice(),
+            [LevelError::MemoryUnreachable { memory: 0, .. }]
+        ));
+    }
+
+    #[test]
+    fn difficulty_without_memories_is_rejected() {
+        let mut level = dream();
+        level.memories.retain(|m| m.from == Difficulty::Hard);
+        assert_eq!(
+            validate(&level),
+            Err(vec![
+                LevelError::NoMemories {
+                    difficulty: Difficulty::Easy
+                },
+                LevelError::NoMemories {
+                    difficulty: Difficulty::Normal
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn start_in_wall_is_rejected() {
+        let mut level = dream();
+        level.player_start = level.walls[2].center;
+        let errors = validate(&level).unwrap_err();
+        assert!(matches!(
+            errors.as_slice(),
+            [LevelError::StartInWall { wall: 2, .. }, ..]
+        ));
+    }
+}
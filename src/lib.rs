@@ -1,32 +1,81 @@
-#![allow(clippy::type_complexity)]
+#![allow(clippy::type_complexity, clippy::too_many_arguments)]
 
 mod actions;
 mod audio;
+mod campaign;
+mod clipboard;
+mod collision;
+mod controls_menu;
+#[cfg(feature = "dev")]
+mod editor;
+mod fever;
+mod highscores;
+mod level;
+mod level_select;
+mod level_validation;
 mod loading;
+mod maze_gen;
 mod menu;
+mod mood;
+mod pathfinding;
+mod pause;
 mod player;
+mod settings_menu;
+mod storage;
+mod text_field;
 
 use crate::actions::ActionsPlugin;
 use crate::audio::InternalAudioPlugin;
+use crate::campaign::CampaignPlugin;
+use crate::controls_menu::ControlsMenuPlugin;
+use crate::fever::FeverPlugin;
+use crate::highscores::HighScoresPlugin;
+use crate::level::LevelPlugin;
 use crate::loading::LoadingPlugin;
 use crate::menu::MenuPlugin;
+use crate::mood::MoodPlugin;
+use crate::pause::PausePlugin;
 use crate::player::PlayerPlugin;
+use crate::settings_menu::SettingsMenuPlugin;
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 // -------------------- Game States --------------------
 #[derive(States, Default, Clone, Eq, PartialEq, Debug, Hash)]
 pub enum GameState {
     #[default]
     Loading,
+    /// An asset failed to load; error screen with Retry / Quit
+    LoadingFailed,
     Menu,
+    Controls,
+    Settings,
+    /// Campaign level select, reached from Start
+    LevelSelect,
     Countdown,
     Playing,
     GameOver,
+    /// Level editor (dev builds only)
+    #[cfg(feature = "dev")]
+    Editor,
+}
+
+/// Pause inside a run (countdown or playing). Anything but `Running` freezes
+/// virtual time, so every timer and the mood physics stop with it.
+#[derive(SubStates, Default, Clone, Copy, Eq, PartialEq, Debug, Hash)]
+#[source(GameState = GameState::Countdown | GameState::Playing)]
+pub enum PauseState {
+    #[default]
+    Running,
+    /// Pause overlay (Resume / Restart / Settings / Menu)
+    Paused,
+    /// Audio settings opened from the pause overlay
+    Settings,
 }
 
 // -------------------- New: Game Config --------------------
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Difficulty {
     Easy,
     Normal,
@@ -43,10 +92,61 @@ impl Difficulty {
     }
 }
 
+/// How the player steers
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ControlScheme {
+    /// Keys / stick / joystick move the player directly
+    #[default]
+    Direct,
+    /// Click or tap a spot and the player paths there around the walls
+    ClickToMove,
+}
+
+impl ControlScheme {
+    pub fn label(self) -> &'static str {
+        match self {
+            ControlScheme::Direct => "Direct",
+            ControlScheme::ClickToMove => "Click to move",
+        }
+    }
+}
+
+/// Where the maze comes from
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum MazeSource {
+    /// The hand-made campaign levels, picked on the level select screen
+    #[default]
+    Campaign,
+    /// Generated from `GameConfig::seed`
+    Generated,
+    /// The layout being edited, started with the editor's test play key
+    #[cfg(feature = "dev")]
+    Edited,
+}
+
+impl MazeSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            MazeSource::Campaign => "Campaign",
+            MazeSource::Generated => "Random",
+            #[cfg(feature = "dev")]
+            MazeSource::Edited => "Edited",
+        }
+    }
+}
+
 #[derive(Resource, Clone, Debug)]
 pub struct GameConfig {
     pub player_name: String,
     pub difficulty: Difficulty,
+    pub control_scheme: ControlScheme,
+    pub maze: MazeSource,
+    /// Campaign level index (into `LevelAssets::campaign`)
+    pub level: usize,
+    /// Seed for generated mazes and the fever spike schedule
+    pub seed: u32,
+    /// Random forced mood shifts during a run (see `fever.rs`)
+    pub fever_spikes: bool,
 }
 
 impl Default for GameConfig {
@@ -54,6 +154,11 @@ impl Default for GameConfig {
         Self {
             player_name: String::new(),
             difficulty: Difficulty::Normal,
+            control_scheme: ControlScheme::Direct,
+            maze: MazeSource::Campaign,
+            level: 0,
+            seed: 0,
+            fever_spikes: false,
         }
     }
 }
@@ -63,13 +168,25 @@ pub struct GamePlugin;
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<GameState>()
+            .add_sub_state::<PauseState>()
             .init_resource::<GameConfig>()
             .add_plugins((
+                LevelPlugin,
+                MoodPlugin,
+                CampaignPlugin,
+                HighScoresPlugin,
                 LoadingPlugin,
                 MenuPlugin,
+                ControlsMenuPlugin,
+                SettingsMenuPlugin,
                 ActionsPlugin,
                 InternalAudioPlugin,
                 PlayerPlugin,
+                FeverPlugin,
+                PausePlugin,
             ));
+
+        #[cfg(feature = "dev")]
+        app.add_plugins(editor::EditorPlugin);
     }
 }
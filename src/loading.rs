@@ -1,7 +1,13 @@
+use bevy::asset::UntypedAssetLoadFailedEvent;
 use bevy::prelude::*;
 use bevy_asset_loader::prelude::*;
+use bevy_kira_audio::AudioSource;
+use iyes_progress::{ProgressPlugin, ProgressTracker};
 
 use crate::GameState;
+use crate::actions::Actions;
+use crate::level::LevelData;
+use crate::mood::MoodSet;
 
 pub struct LoadingPlugin;
 
@@ -13,34 +19,149 @@ pub struct TextureAssets {
     pub github: Handle<Image>,
 }
 
+#[derive(AssetCollection, Resource)]
+pub struct AudioAssets {
+    #[asset(path = "audio/flying.ogg")]
+    pub music: Handle<AudioSource>,
+    #[asset(path = "audio/memory.wav")]
+    pub memory: Handle<AudioSource>,
+    #[asset(path = "audio/tick.wav")]
+    pub tick: Handle<AudioSource>,
+    #[asset(path = "audio/go.wav")]
+    pub go: Handle<AudioSource>,
+    #[asset(path = "audio/hurry.wav")]
+    pub hurry: Handle<AudioSource>,
+    #[asset(path = "audio/bump.wav")]
+    pub bump: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+pub struct LevelAssets {
+    /// Campaign levels, in play order
+    #[asset(
+        paths(
+            "levels/dream.level.ron",
+            "levels/corridors.level.ron",
+            "levels/spiral.level.ron"
+        ),
+        collection(typed)
+    )]
+    pub campaign: Vec<Handle<LevelData>>,
+}
+
+#[derive(AssetCollection, Resource)]
+pub struct MoodAssets {
+    #[asset(path = "moods/fever.moods.ron")]
+    pub set: Handle<MoodSet>,
+}
+
 #[derive(Component)]
 struct LoadingTag;
 
 #[derive(Component)]
 struct LoadingIconRow;
 
+#[derive(Component)]
+struct ProgressFill;
+
+#[derive(Component)]
+struct ProgressText;
+
 #[derive(Resource, Default)]
 struct LoadingUiState {
     icons_spawned: bool,
 }
 
+/// Handles of every collection, only to name the file still loading (progress comes from the tracker)
+#[derive(Resource, Default)]
+struct LoadingHandles(Vec<UntypedHandle>);
+
+/// Files that failed during the current loading attempt: path and reason
+#[derive(Resource, Default)]
+struct LoadFailures(Vec<(String, String)>);
+
+#[derive(Component)]
+struct FailedTag;
+
+#[derive(Component)]
+struct RetryButton;
+
+#[derive(Component)]
+struct QuitButton;
+
 impl Plugin for LoadingPlugin {
     fn build(&self, app: &mut App) {
-        // Load assets, then move to Menu
-        app.add_loading_state(
+        // Load assets, then move to Menu once every collection reports done
+        // (or to the error screen if a file is missing / broken)
+        app.add_plugins(
+            ProgressPlugin::<GameState>::new()
+                .with_state_transition(GameState::Loading, GameState::Menu),
+        )
+        .add_loading_state(
             LoadingState::new(GameState::Loading)
-                .continue_to_state(GameState::Menu)
-                .load_collection::<TextureAssets>(),
+                .on_failure_continue_to_state(GameState::LoadingFailed)
+                .load_collection::<TextureAssets>()
+                .load_collection::<AudioAssets>()
+                .load_collection::<LevelAssets>()
+                .load_collection::<MoodAssets>(),
         );
 
         // Visible “Loading…” UI
-        app.add_systems(OnEnter(GameState::Loading), setup_loading_ui);
+        app.add_systems(
+            OnEnter(GameState::Loading),
+            (
+                setup_loading_ui,
+                reset_load_failures,
+                collect_loading_handles,
+            ),
+        );
         // Spawn icons once assets are actually available
         app.add_systems(
             Update,
-            spawn_loading_icons.run_if(in_state(GameState::Loading)),
+            (
+                spawn_loading_icons,
+                record_load_failures,
+                update_loading_progress.after(LoadingStateSet(GameState::Loading)),
+            )
+                .run_if(in_state(GameState::Loading)),
         );
         app.add_systems(OnExit(GameState::Loading), cleanup_loading_ui);
+
+        // Failure screen
+        app.add_systems(OnEnter(GameState::LoadingFailed), setup_failed_ui)
+            .add_systems(
+                Update,
+                (failed_input, failed_buttons, failed_visuals)
+                    .chain()
+                    .run_if(in_state(GameState::LoadingFailed)),
+            )
+            .add_systems(OnExit(GameState::LoadingFailed), cleanup_failed_ui);
+    }
+}
+
+/// Ask the collections for their handles (`AssetServer::load` dedups, so nothing loads twice)
+fn collect_loading_handles(world: &mut World) {
+    let mut handles = TextureAssets::load(world);
+    handles.extend(AudioAssets::load(world));
+    handles.extend(LevelAssets::load(world));
+    handles.extend(MoodAssets::load(world));
+    world.insert_resource(LoadingHandles(handles));
+}
+
+fn reset_load_failures(mut commands: Commands) {
+    commands.insert_resource(LoadFailures::default());
+}
+
+/// Remember why files failed, for the error screen
+fn record_load_failures(
+    mut failed: MessageReader<UntypedAssetLoadFailedEvent>,
+    mut failures: ResMut<LoadFailures>,
+) {
+    for e in failed.read() {
+        let path = e.path.to_string();
+        if !failures.0.iter().any(|(p, _)| *p == path) {
+            failures.0.push((path, e.error.to_string()));
+        }
     }
 }
 
@@ -81,6 +202,42 @@ fn setup_loading_ui(mut commands: Commands) {
                     TextColor(Color::WHITE),
                 ));
 
+                // Progress bar
+                col.spawn((
+                    LoadingTag,
+                    Node {
+                        width: Val::Px(420.0),
+                        height: Val::Px(16.0),
+                        border: UiRect::all(Val::Px(1.0)),
+                        border_radius: BorderRadius::all(Val::Px(8.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.14, 0.14, 0.16)),
+                    BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
+                ))
+                .with_child((
+                    LoadingTag,
+                    ProgressFill,
+                    Node {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        border_radius: BorderRadius::all(Val::Px(8.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.20, 0.90, 0.95)),
+                ));
+
+                col.spawn((
+                    LoadingTag,
+                    ProgressText,
+                    Text::new(""),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.70, 0.70, 0.74)),
+                ));
+
                 // Icons will be inserted here once TextureAssets exists
                 col.spawn((
                     LoadingTag,
@@ -140,9 +297,240 @@ fn spawn_loading_icons(
     });
 }
 
+fn update_loading_progress(
+    tracker: Res<ProgressTracker<GameState>>,
+    asset_server: Res<AssetServer>,
+    handles: Option<Res<LoadingHandles>>,
+    mut q_fill: Query<&mut Node, With<ProgressFill>>,
+    mut q_text: Query<&mut Text, With<ProgressText>>,
+) {
+    let progress = tracker.get_global_progress();
+    if progress.total == 0 {
+        return;
+    }
+
+    for mut node in &mut q_fill {
+        node.width = Val::Percent(f32::from(progress) * 100.0);
+    }
+    let current = handles
+        .iter()
+        .flat_map(|h| h.0.iter())
+        .find(|h| !asset_server.is_loaded_with_dependencies(h.id()))
+        .and_then(|h| asset_server.get_path(h.id()))
+        .map(|p| p.to_string());
+    let (done, total) = (progress.done, progress.total);
+    for mut text in &mut q_text {
+        *text = Text::new(match &current {
+            Some(path) => format!("{path}  ({done}/{total})"),
+            None => format!("Ready  ({done}/{total})"),
+        });
+    }
+}
+
 fn cleanup_loading_ui(mut commands: Commands, q: Query<Entity, With<LoadingTag>>) {
     for e in &q {
         commands.entity(e).despawn();
     }
     commands.remove_resource::<LoadingUiState>();
+    commands.remove_resource::<LoadingHandles>();
+}
+
+/* ----------------------- LOAD FAILURE ----------------------- */
+
+fn setup_failed_ui(mut commands: Commands, failures: Option<Res<LoadFailures>>) {
+    let failures = failures.map(|f| f.0.clone()).unwrap_or_default();
+    for (path, err) in &failures {
+        error!("Failed to load '{path}': {err}");
+    }
+
+    commands
+        .spawn((
+            FailedTag,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.10)),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                FailedTag,
+                Node {
+                    width: Val::Px(760.0),
+                    padding: UiRect::all(Val::Px(24.0)),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(12.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    border_radius: BorderRadius::all(Val::Px(18.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.09, 0.09, 0.11)),
+                BorderColor::all(Color::srgb(0.55, 0.22, 0.28)),
+            ))
+            .with_children(|card| {
+                card.spawn((
+                    FailedTag,
+                    Text::new("Could not load the game"),
+                    TextFont {
+                        font_size: 36.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.95, 0.35, 0.45)),
+                ));
+
+                card.spawn((
+                    FailedTag,
+                    Text::new("These files are missing or broken:"),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.76, 0.76, 0.80)),
+                ));
+
+                if failures.is_empty() {
+                    card.spawn((
+                        FailedTag,
+                        Text::new("(unknown asset)"),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                }
+
+                for (path, err) in &failures {
+                    card.spawn((
+                        FailedTag,
+                        Text::new(path.clone()),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                    card.spawn((
+                        FailedTag,
+                        Text::new(err.clone()),
+                        TextFont {
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.60, 0.60, 0.66)),
+                    ));
+                }
+
+                card.spawn((
+                    FailedTag,
+                    Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(12.0),
+                        margin: UiRect::top(Val::Px(10.0)),
+                        ..default()
+                    },
+                ))
+                .with_children(|row| {
+                    for (label, retry) in [("RETRY", true), ("QUIT", false)] {
+                        let mut button = row.spawn((
+                            FailedTag,
+                            Button,
+                            Node {
+                                width: Val::Px(200.0),
+                                height: Val::Px(52.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                border: UiRect::all(Val::Px(1.0)),
+                                border_radius: BorderRadius::all(Val::Px(14.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(0.14, 0.14, 0.16)),
+                            BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
+                        ));
+                        if retry {
+                            button.insert(RetryButton);
+                        } else {
+                            button.insert(QuitButton);
+                        }
+                        button.with_child((
+                            FailedTag,
+                            Text::new(label),
+                            TextFont {
+                                font_size: 22.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                    }
+                });
+            });
+        });
+}
+
+/// Confirm retries, Cancel quits
+fn failed_input(
+    actions: Res<Actions>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    if actions.confirm {
+        next_state.set(GameState::Loading);
+    } else if actions.cancel {
+        exit.write(AppExit::Success);
+    }
+}
+
+fn failed_buttons(
+    q: Query<
+        (&Interaction, Option<&RetryButton>, Option<&QuitButton>),
+        (With<Button>, Changed<Interaction>),
+    >,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    for (i, retry, quit) in &q {
+        if *i != Interaction::Pressed {
+            continue;
+        }
+
+        if retry.is_some() {
+            // Re-entering Loading asks the asset server again; failed files are retried
+            next_state.set(GameState::Loading);
+        } else if quit.is_some() {
+            exit.write(AppExit::Success);
+        }
+    }
+}
+
+fn failed_visuals(
+    mut q: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (With<Button>, With<FailedTag>),
+    >,
+) {
+    for (i, mut bg, mut border) in &mut q {
+        match *i {
+            Interaction::Pressed => {
+                bg.0 = Color::srgb(0.24, 0.24, 0.30);
+                *border = BorderColor::all(Color::srgb(0.85, 0.25, 0.95));
+            }
+            Interaction::Hovered => {
+                bg.0 = Color::srgb(0.20, 0.20, 0.24);
+                *border = BorderColor::all(Color::srgb(0.20, 0.90, 0.95));
+            }
+            Interaction::None => {
+                bg.0 = Color::srgb(0.14, 0.14, 0.16);
+                *border = BorderColor::all(Color::srgb(0.22, 0.22, 0.28));
+            }
+        }
+    }
+}
+
+fn cleanup_failed_ui(mut commands: Commands, q: Query<Entity, With<FailedTag>>) {
+    for e in &q {
+        commands.entity(e).despawn();
+    }
 }
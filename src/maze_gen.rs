@@ -0,0 +1,259 @@
+//! Seeded maze generator.
+//!
+//! The arena is split into a grid of rooms; a depth-first carve gives a perfect
+//! maze, then some dead ends are knocked open (braided) depending on difficulty.
+//! Memories are only placed in rooms a flood fill from the player start reaches.
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::{Rng, SeedableRng};
+
+use crate::Difficulty;
+use crate::level::{LevelData, MemorySpawn, TimeLimits, WallData};
+
+/// Inner wall thickness
+const WALL_THICKNESS: f32 = 20.0;
+/// Resolution of the reachability grid
+const PROOF_CELL: f32 = 8.0;
+
+/// Room size and how many dead ends survive
+struct MazeParams {
+    /// Room edge length (corridor width + one wall)
+    room: f32,
+    /// Chance that a dead end gets opened into a loop
+    braid: f64,
+    memories: usize,
+}
+
+impl MazeParams {
+    fn for_difficulty(difficulty: Difficulty) -> Self {
+        match difficulty {
+            Difficulty::Easy => Self {
+                room: 150.0,
+                braid: 0.75,
+                memories: 5,
+            },
+            Difficulty::Normal => Self {
+                room: 120.0,
+                braid: 0.4,
+                memories: 7,
+            },
+            Difficulty::Hard => Self {
+                room: 96.0,
+                braid: 0.1,
+                memories: 11,
+            },
+        }
+    }
+}
+
+/// Open passages between rooms, indexed `row * cols + col`
+struct Grid {
+    cols: usize,
+    rows: usize,
+    /// Passage to the room on the right
+    east: Vec<bool>,
+    /// Passage to the room above
+    north: Vec<bool>,
+}
+
+impl Grid {
+    fn new(cols: usize, rows: usize) -> Self {
+        Self {
+            cols,
+            rows,
+            east: vec![false; cols * rows],
+            north: vec![false; cols * rows],
+        }
+    }
+
+    fn index(&self, (c, r): (usize, usize)) -> usize {
+        r * self.cols + c
+    }
+
+    fn neighbours(&self, (c, r): (usize, usize)) -> Vec<(usize, usize)> {
+        let mut out = Vec::with_capacity(4);
+        if c > 0 {
+            out.push((c - 1, r));
+        }
+        if c + 1 < self.cols {
+            out.push((c + 1, r));
+        }
+        if r > 0 {
+            out.push((c, r - 1));
+        }
+        if r + 1 < self.rows {
+            out.push((c, r + 1));
+        }
+        out
+    }
+
+    fn is_open(&self, a: (usize, usize), b: (usize, usize)) -> bool {
+        match (b.0 as i32 - a.0 as i32, b.1 as i32 - a.1 as i32) {
+            (1, 0) => self.east[self.index(a)],
+            (-1, 0) => self.east[self.index(b)],
+            (0, 1) => self.north[self.index(a)],
+            (0, -1) => self.north[self.index(b)],
+            _ => false,
+        }
+    }
+
+    fn open(&mut self, a: (usize, usize), b: (usize, usize)) {
+        match (b.0 as i32 - a.0 as i32, b.1 as i32 - a.1 as i32) {
+            (1, 0) => {
+                let i = self.index(a);
+                self.east[i] = true;
+            }
+            (-1, 0) => {
+                let i = self.index(b);
+                self.east[i] = true;
+            }
+            (0, 1) => {
+                let i = self.index(a);
+                self.north[i] = true;
+            }
+            (0, -1) => {
+                let i = self.index(b);
+                self.north[i] = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn exits(&self, room: (usize, usize)) -> usize {
+        self.neighbours(room)
+            .into_iter()
+            .filter(|n| self.is_open(room, *n))
+            .count()
+    }
+
+    /// Depth-first carve from `start`: every room ends up connected
+    fn carve(&mut self, start: (usize, usize), rng: &mut StdRng) {
+        let mut visited = vec![false; self.cols * self.rows];
+        let mut stack = vec![start];
+        visited[self.index(start)] = true;
+
+        while let Some(&room) = stack.last() {
+            let unvisited: Vec<_> = self
+                .neighbours(room)
+                .into_iter()
+                .filter(|n| !visited[self.index(*n)])
+                .collect();
+
+            match unvisited.choose(rng) {
+                Some(&next) => {
+                    self.open(room, next);
+                    visited[self.index(next)] = true;
+                    stack.push(next);
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    /// Open some dead ends into loops (fewer dead ends = easier)
+    fn braid(&mut self, chance: f64, rng: &mut StdRng) {
+        let mut rooms: Vec<_> = (0..self.rows)
+            .flat_map(|r| (0..self.cols).map(move |c| (c, r)))
+            .collect();
+        rooms.shuffle(rng);
+
+        for room in rooms {
+            if self.exits(room) != 1 || !rng.random_bool(chance) {
+                continue;
+            }
+            let closed: Vec<_> = self
+                .neighbours(room)
+                .into_iter()
+                .filter(|n| !self.is_open(room, *n))
+                .collect();
+            // Prefer joining two dead ends, that removes both
+            let pick = closed
+                .iter()
+                .find(|n| self.exits(**n) == 1)
+                .or_else(|| closed.choose(rng))
+                .copied();
+            if let Some(n) = pick {
+                self.open(room, n);
+            }
+        }
+    }
+}
+
+/// Build a maze level for `seed`. Same seed + arena + difficulty = same maze.
+pub fn generate(seed: u32, half_extents: Vec2, difficulty: Difficulty) -> LevelData {
+    let params = MazeParams::for_difficulty(difficulty);
+    let mut rng = StdRng::seed_from_u64(seed as u64);
+
+    let size = half_extents * 2.0;
+    let cols = ((size.x / params.room).floor() as usize).max(2);
+    let rows = ((size.y / params.room).floor() as usize).max(2);
+    // Stretch rooms so the grid fills the arena exactly
+    let room = Vec2::new(size.x / cols as f32, size.y / rows as f32);
+    let center_of =
+        |(c, r): (usize, usize)| -half_extents + Vec2::new(c as f32 + 0.5, r as f32 + 0.5) * room;
+
+    let start_room = (0, rows / 2);
+    let mut grid = Grid::new(cols, rows);
+    grid.carve(start_room, &mut rng);
+    grid.braid(params.braid, &mut rng);
+
+    // Inner walls only, the arena border closes the outside
+    let mut walls = Vec::new();
+    for r in 0..rows {
+        for c in 0..cols {
+            let i = grid.index((c, r));
+            let center = center_of((c, r));
+            if c + 1 < cols && !grid.east[i] {
+                walls.push(WallData {
+                    center: center + Vec2::new(room.x * 0.5, 0.0),
+                    size: Vec2::new(WALL_THICKNESS, room.y + WALL_THICKNESS),
+                });
+            }
+            if r + 1 < rows && !grid.north[i] {
+                walls.push(WallData {
+                    center: center + Vec2::new(0.0, room.y * 0.5),
+                    size: Vec2::new(room.x + WALL_THICKNESS, WALL_THICKNESS),
+                });
+            }
+        }
+    }
+
+    let mut level = LevelData {
+        name: format!("Seed {seed}"),
+        half_extents,
+        player_start: center_of(start_room),
+        time_limit: TimeLimits {
+            easy: 60.0,
+            normal: 45.0,
+            hard: 30.0,
+        },
+        walls,
+        memories: Vec::new(),
+    };
+
+    // Flood fill from the spawn; only rooms it reaches may hold a memory
+    let nav = level.nav_grid(PROOF_CELL);
+    let reached = nav.reachable_from(nav.cell_of(level.player_start));
+    let mut candidates: Vec<Vec2> = (0..rows)
+        .flat_map(|r| (0..cols).map(move |c| (c, r)))
+        .filter(|&(c, r)| c.abs_diff(start_room.0) + r.abs_diff(start_room.1) > 1)
+        .map(center_of)
+        .filter(|p| nav.is_reachable(&reached, nav.cell_of(*p)))
+        .collect();
+    candidates.shuffle(&mut rng);
+
+    level.memories = candidates
+        .into_iter()
+        .take(params.memories)
+        .map(|pos| MemorySpawn {
+            pos,
+            from: Difficulty::Easy,
+        })
+        .collect();
+
+    level
+}
@@ -1,9 +1,23 @@
 use bevy::app::AppExit;
-use bevy::ecs::message::MessageWriter;
+use bevy::ecs::message::{MessageReader, MessageWriter};
 use bevy::ecs::schedule::IntoScheduleConfigs; // <-- IMPORTANT for .run_if(...)
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
 use bevy::prelude::*;
 
-use crate::{Difficulty, GameConfig, GameState};
+use crate::actions::{Actions, GameControl, InputBindings};
+use crate::clipboard;
+use crate::highscores::{HighScores, board_name, format_date};
+use crate::level::LevelData;
+use crate::level_select::LevelSelectPlugin;
+use crate::loading::LevelAssets;
+use crate::mood::Moods;
+use crate::player::mood_keys_hint;
+use crate::text_field::TextField;
+use crate::{ControlScheme, Difficulty, GameConfig, GameState, MazeSource};
+
+const NAME_MAX_CHARS: usize = 18;
+const NAME_PLACEHOLDER: &str = "Type your name…";
 
 pub struct MenuPlugin;
 
@@ -22,26 +36,96 @@ struct NameBox;
 #[derive(Component)]
 struct NameText;
 
+#[derive(Component)]
+struct NameCaret;
+
+#[derive(Component)]
+struct NameAfterCaret;
+
+/// Live state of the name box; `GameConfig::player_name` mirrors its text
+#[derive(Resource)]
+struct NameField {
+    field: TextField,
+    /// Restarts the caret blink so it stays visible while typing
+    last_edit: f32,
+}
+
 #[derive(Component)]
 struct StartButton;
 
 #[derive(Component)]
 struct QuitButton;
 
+#[derive(Component)]
+struct ControlsButton;
+
+#[derive(Component)]
+struct SettingsButton;
+
 #[derive(Component)]
 struct DifficultyButton(Difficulty);
 
+#[derive(Component)]
+struct ControlSchemeButton(ControlScheme);
+
+#[derive(Component)]
+struct MazeSourceButton(MazeSource);
+
+/// On / off for fever spikes
+#[derive(Component)]
+struct FeverSpikesButton(bool);
+
+#[derive(Component)]
+struct LeaderboardTitle;
+
+#[derive(Component)]
+struct LeaderboardRows;
+
+#[cfg(feature = "dev")]
+#[derive(Component)]
+struct EditorButton;
+
 impl Plugin for MenuPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(GameState::Menu), setup_menu)
-            .add_systems(Update, menu_name_input.run_if(in_state(GameState::Menu)))
+        clipboard::install();
+
+        app.add_plugins(LevelSelectPlugin)
+            .add_systems(OnEnter(GameState::Menu), setup_menu)
+            .add_systems(
+                Update,
+                (menu_name_input, update_name_text)
+                    .chain()
+                    .run_if(in_state(GameState::Menu)),
+            )
             .add_systems(Update, menu_buttons.run_if(in_state(GameState::Menu)))
             .add_systems(Update, menu_visuals.run_if(in_state(GameState::Menu)))
+            .add_systems(
+                Update,
+                refresh_leaderboard.run_if(in_state(GameState::Menu)),
+            )
             .add_systems(OnExit(GameState::Menu), cleanup_menu);
+
+        #[cfg(feature = "dev")]
+        app.add_systems(Update, editor_button.run_if(in_state(GameState::Menu)));
     }
 }
 
-fn setup_menu(mut commands: Commands, config: Res<GameConfig>) {
+fn setup_menu(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    bindings: Res<InputBindings>,
+    moods: Moods,
+) {
+    let key = |c| bindings.primary_label(c);
+
+    commands.insert_resource(NameField {
+        field: TextField::new(&config.player_name, NAME_MAX_CHARS),
+        last_edit: time.elapsed_secs(),
+    });
+    // The name box has focus for as long as the menu is up
+    clipboard::set_focused(true);
+
     commands
         .spawn((
             MenuTag,
@@ -50,6 +134,7 @@ fn setup_menu(mut commands: Commands, config: Res<GameConfig>) {
                 height: Val::Percent(100.0),
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
+                column_gap: Val::Px(24.0),
                 ..default()
             },
             BackgroundColor(Color::srgb(0.05, 0.05, 0.06)),
@@ -166,9 +251,14 @@ fn setup_menu(mut commands: Commands, config: Res<GameConfig>) {
 
                 card.spawn((
                     MenuTag,
-                    Text::new(
-                        "Collect the memories before time runs out.\nWASD/Arrows to move • 1/2/3 to change mood",
-                    ),
+                    Text::new(format!(
+                        "Collect the memories before time runs out.\n{}{}{}{}/Stick to move • {} to change mood",
+                        key(GameControl::Up),
+                        key(GameControl::Left),
+                        key(GameControl::Down),
+                        key(GameControl::Right),
+                        mood_keys_hint(&bindings, moods.all().len()),
+                    )),
                     TextFont {
                         font_size: 20.0,
                         ..default()
@@ -204,22 +294,39 @@ fn setup_menu(mut commands: Commands, config: Res<GameConfig>) {
                     BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
                 ))
                 .with_children(|row| {
-                    let shown = if config.player_name.trim().is_empty() {
-                        "Type your name…".to_string()
-                    } else {
-                        config.player_name.clone()
-                    };
-
+                    // [text before caret][caret][text after caret] — filled by update_name_text
                     row.spawn((
                         MenuTag,
                         NameText,
-                        Text::new(shown),
+                        Text::new(""),
                         TextFont {
                             font_size: 22.0,
                             ..default()
                         },
                         TextColor(Color::srgb(0.93, 0.93, 0.96)),
-                    ));
+                    ))
+                    .with_children(|text| {
+                        text.spawn((
+                            MenuTag,
+                            NameCaret,
+                            TextSpan::new("|"),
+                            TextFont {
+                                font_size: 22.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.20, 0.90, 0.95)),
+                        ));
+                        text.spawn((
+                            MenuTag,
+                            NameAfterCaret,
+                            TextSpan::new(""),
+                            TextFont {
+                                font_size: 22.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.93, 0.93, 0.96)),
+                        ));
+                    });
                 });
 
                 // Difficulty label
@@ -278,6 +385,100 @@ fn setup_menu(mut commands: Commands, config: Res<GameConfig>) {
                     }
                 });
 
+                // Movement + maze selectors, side by side
+                card.spawn((
+                    MenuTag,
+                    Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(28.0),
+                        ..default()
+                    },
+                ))
+                .with_children(|row| {
+                    row.spawn((
+                        MenuTag,
+                        Node {
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(8.0),
+                            ..default()
+                        },
+                    ))
+                    .with_children(|col| {
+                        spawn_option_label(col, "MOVEMENT");
+                        col.spawn((
+                            MenuTag,
+                            Node {
+                                flex_direction: FlexDirection::Row,
+                                column_gap: Val::Px(12.0),
+                                ..default()
+                            },
+                        ))
+                        .with_children(|buttons| {
+                            for scheme in [ControlScheme::Direct, ControlScheme::ClickToMove] {
+                                spawn_option_button(
+                                    buttons,
+                                    ControlSchemeButton(scheme),
+                                    scheme.label(),
+                                );
+                            }
+                        });
+                    });
+
+                    row.spawn((
+                        MenuTag,
+                        Node {
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(8.0),
+                            ..default()
+                        },
+                    ))
+                    .with_children(|col| {
+                        spawn_option_label(col, "MAZE");
+                        col.spawn((
+                            MenuTag,
+                            Node {
+                                flex_direction: FlexDirection::Row,
+                                column_gap: Val::Px(12.0),
+                                ..default()
+                            },
+                        ))
+                        .with_children(|buttons| {
+                            for source in [MazeSource::Campaign, MazeSource::Generated] {
+                                spawn_option_button(
+                                    buttons,
+                                    MazeSourceButton(source),
+                                    source.label(),
+                                );
+                            }
+                        });
+                    });
+                });
+
+                card.spawn((
+                    MenuTag,
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(8.0),
+                        ..default()
+                    },
+                ))
+                .with_children(|col| {
+                    spawn_option_label(col, "FEVER SPIKES");
+                    col.spawn((
+                        MenuTag,
+                        Node {
+                            flex_direction: FlexDirection::Row,
+                            column_gap: Val::Px(12.0),
+                            ..default()
+                        },
+                    ))
+                    .with_children(|buttons| {
+                        for (on, label) in [(false, "Off"), (true, "On")] {
+                            spawn_option_button(buttons, FeverSpikesButton(on), label);
+                        }
+                    });
+                });
+
                 // Actions row
                 card.spawn((
                     MenuTag,
@@ -294,7 +495,7 @@ fn setup_menu(mut commands: Commands, config: Res<GameConfig>) {
                         Button,
                         StartButton,
                         Node {
-                            width: Val::Px(240.0),
+                            width: Val::Px(220.0),
                             height: Val::Px(58.0),
                             justify_content: JustifyContent::Center,
                             align_items: AlignItems::Center,
@@ -315,12 +516,64 @@ fn setup_menu(mut commands: Commands, config: Res<GameConfig>) {
                         TextColor(Color::WHITE),
                     ));
 
+                    row.spawn((
+                        MenuTag,
+                        Button,
+                        ControlsButton,
+                        Node {
+                            width: Val::Px(160.0),
+                            height: Val::Px(58.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(1.0)),
+                            border_radius: BorderRadius::all(Val::Px(14.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.14, 0.14, 0.16)),
+                        BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
+                    ))
+                    .with_child((
+                        MenuTag,
+                        Text::new("CONTROLS"),
+                        TextFont {
+                            font_size: 24.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+
+                    row.spawn((
+                        MenuTag,
+                        Button,
+                        SettingsButton,
+                        Node {
+                            width: Val::Px(160.0),
+                            height: Val::Px(58.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(1.0)),
+                            border_radius: BorderRadius::all(Val::Px(14.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.14, 0.14, 0.16)),
+                        BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
+                    ))
+                    .with_child((
+                        MenuTag,
+                        Text::new("SETTINGS"),
+                        TextFont {
+                            font_size: 24.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+
                     row.spawn((
                         MenuTag,
                         Button,
                         QuitButton,
                         Node {
-                            width: Val::Px(170.0),
+                            width: Val::Px(120.0),
                             height: Val::Px(58.0),
                             justify_content: JustifyContent::Center,
                             align_items: AlignItems::Center,
@@ -344,68 +597,327 @@ fn setup_menu(mut commands: Commands, config: Res<GameConfig>) {
 
                 card.spawn((
                     MenuTag,
-                    Text::new("Keyboard: type name • Enter = Start • Esc = Quit"),
+                    Text::new(format!(
+                        "Keyboard: type name • {} = Start",
+                        key(GameControl::Confirm),
+                    )),
                     TextFont {
                         font_size: 16.0,
                         ..default()
                     },
                     TextColor(Color::srgb(0.70, 0.70, 0.74)),
                 ));
+
+                #[cfg(feature = "dev")]
+                card.spawn((
+                    MenuTag,
+                    Button,
+                    EditorButton,
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(36.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(1.0)),
+                        border_radius: BorderRadius::all(Val::Px(12.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.14, 0.14, 0.16)),
+                    BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
+                ))
+                .with_child((
+                    MenuTag,
+                    Text::new("LEVEL EDITOR (dev)"),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            });
+
+            // Leaderboard panel (filled by refresh_leaderboard)
+            root.spawn((
+                MenuTag,
+                Node {
+                    width: Val::Px(340.0),
+                    padding: UiRect::all(Val::Px(20.0)),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(10.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    border_radius: BorderRadius::all(Val::Px(18.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.09, 0.09, 0.11)),
+                BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
+            ))
+            .with_children(|panel| {
+                panel.spawn((
+                    MenuTag,
+                    Text::new("HIGH SCORES"),
+                    TextFont {
+                        font_size: 26.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.95, 0.95, 0.98)),
+                ));
+                panel.spawn((
+                    MenuTag,
+                    LeaderboardTitle,
+                    Text::new(""),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.76, 0.76, 0.80)),
+                ));
+                panel.spawn((
+                    MenuTag,
+                    LeaderboardRows,
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(6.0),
+                        ..default()
+                    },
+                ));
             });
         });
 }
 
-fn menu_name_input(
-    keys: Res<ButtonInput<KeyCode>>,
-    mut config: ResMut<GameConfig>,
-    mut next_state: ResMut<NextState<GameState>>,
-    mut exit: MessageWriter<AppExit>,
-    mut q_name: Query<&mut Text, With<NameText>>,
+/// Show the table for the selected difficulty and maze (campaign: the current level)
+fn refresh_leaderboard(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    scores: Res<HighScores>,
+    level_assets: Res<LevelAssets>,
+    levels: Res<Assets<LevelData>>,
+    added: Query<(), Added<LeaderboardRows>>,
+    mut q_title: Query<&mut Text, With<LeaderboardTitle>>,
+    q_rows: Query<Entity, With<LeaderboardRows>>,
 ) {
-    if keys.just_pressed(KeyCode::Escape) {
-        exit.write(AppExit::Success);
+    if !config.is_changed() && added.is_empty() {
         return;
     }
 
-    if keys.just_pressed(KeyCode::Enter) {
-        next_state.set(GameState::Countdown);
-        return;
+    let level = level_assets
+        .campaign
+        .get(
+            config
+                .level
+                .min(level_assets.campaign.len().saturating_sub(1)),
+        )
+        .and_then(|h| levels.get(h));
+    let board = board_name(config.maze, level);
+
+    for mut text in &mut q_title {
+        *text = Text::new(match &board {
+            Some(board) => format!("{board} • {}", config.difficulty.label()),
+            None => "No high scores for this maze".to_string(),
+        });
     }
 
-    let shift = keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight);
+    let table = board
+        .as_deref()
+        .map_or(&[][..], |b| scores.table(b, config.difficulty));
+    let me = config.player_name.trim();
 
-    let mut changed = false;
-    for key in keys.get_just_pressed() {
-        match *key {
-            KeyCode::Backspace | KeyCode::Delete => {
-                changed = config.player_name.pop().is_some() || changed;
+    for rows in &q_rows {
+        commands.entity(rows).despawn_related::<Children>();
+        commands.entity(rows).with_children(|rows| {
+            if board.is_some() && table.is_empty() {
+                spawn_leaderboard_row(rows, "No runs yet".to_string(), String::new(), false);
             }
-            KeyCode::Space => {
-                if config.player_name.len() < 18 {
-                    config.player_name.push(' ');
-                    changed = true;
-                }
+            for (i, entry) in table.iter().enumerate() {
+                let time = entry
+                    .clear_time
+                    .map_or("time up".to_string(), |t| format!("{t:.1}s"));
+                spawn_leaderboard_row(
+                    rows,
+                    format!("{}. {}", i + 1, entry.name),
+                    format!("{}  •  {time}  •  {}", entry.score, format_date(entry.date)),
+                    !me.is_empty() && entry.name == me,
+                );
             }
-            _ => {
-                if let Some(ch) = keycode_to_char(*key, shift) {
-                    if config.player_name.len() < 18 {
-                        config.player_name.push(ch);
-                        changed = true;
-                    }
-                }
+        });
+    }
+}
+
+fn spawn_leaderboard_row(
+    parent: &mut ChildSpawnerCommands,
+    left: String,
+    right: String,
+    highlight: bool,
+) {
+    let color = if highlight {
+        Color::srgb(0.35, 0.9, 0.95)
+    } else {
+        Color::srgb(0.86, 0.86, 0.90)
+    };
+
+    parent
+        .spawn((
+            MenuTag,
+            Node {
+                justify_content: JustifyContent::SpaceBetween,
+                column_gap: Val::Px(10.0),
+                ..default()
+            },
+        ))
+        .with_children(|row| {
+            for text in [left, right] {
+                row.spawn((
+                    MenuTag,
+                    Text::new(text),
+                    TextFont {
+                        font_size: 15.0,
+                        ..default()
+                    },
+                    TextColor(color),
+                ));
             }
+        });
+}
+
+/// Campaign runs pick a level first, random mazes start right away
+fn start_state(config: &GameConfig) -> GameState {
+    match config.maze {
+        MazeSource::Campaign => GameState::LevelSelect,
+        _ => GameState::Countdown,
+    }
+}
+
+fn spawn_option_label(parent: &mut ChildSpawnerCommands, label: &str) {
+    parent.spawn((
+        MenuTag,
+        Text::new(label),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.76, 0.76, 0.80)),
+    ));
+}
+
+/// Toggle-style button for one value of a menu option
+fn spawn_option_button(parent: &mut ChildSpawnerCommands, marker: impl Component, label: &str) {
+    parent
+        .spawn((
+            MenuTag,
+            Button,
+            marker,
+            Node {
+                width: Val::Px(160.0),
+                height: Val::Px(44.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(1.0)),
+                border_radius: BorderRadius::all(Val::Px(14.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.14, 0.14, 0.16)),
+            BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
+        ))
+        .with_child((
+            MenuTag,
+            Text::new(label),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+}
+
+fn menu_name_input(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<Actions>,
+    mut keyboard: MessageReader<KeyboardInput>,
+    mut name: ResMut<NameField>,
+    mut config: ResMut<GameConfig>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    // Cancel does nothing here: a stray Esc shouldn't close the game, QUIT is a button
+    if actions.confirm {
+        next_state.set(start_state(&config));
+        return;
+    }
+
+    // AltGr arrives as Ctrl + Alt on Windows, and it types characters (@, €, ...)
+    let shortcut = keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]) && !keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]);
+
+    // Only flag NameField as changed when an edit actually happened
+    let field = &mut name.bypass_change_detection().field;
+    let mut changed = false;
+    let mut paste_pressed = false;
+
+    for ev in keyboard.read() {
+        if ev.state != ButtonState::Pressed {
+            continue;
         }
+
+        changed |= match &ev.logical_key {
+            Key::Backspace => field.backspace(),
+            Key::Delete => field.delete(),
+            Key::ArrowLeft => field.move_left(),
+            Key::ArrowRight => field.move_right(),
+            Key::Home => field.move_home(),
+            Key::End => field.move_end(),
+            _ if shortcut => {
+                paste_pressed |= ev.key_code == KeyCode::KeyV;
+                false
+            }
+            _ => ev.text.as_ref().is_some_and(|t| field.insert_str(t)),
+        };
+    }
+
+    if let Some(pasted) = clipboard::poll_paste(paste_pressed) {
+        // Names are single-line: flatten whatever was copied
+        changed |= field.insert_str(&pasted.replace(['\n', '\r', '\t'], " "));
     }
 
     if changed {
-        let shown = if config.player_name.trim().is_empty() {
-            "Type your name…".to_string()
-        } else {
-            config.player_name.clone()
-        };
+        config.player_name = field.text().to_string();
+        name.last_edit = time.elapsed_secs();
+    }
+}
+
+fn update_name_text(
+    time: Res<Time>,
+    name: Res<NameField>,
+    mut q_before: Query<&mut Text, With<NameText>>,
+    mut q_caret: Query<&mut TextColor, (With<NameCaret>, Without<NameAfterCaret>)>,
+    mut q_after: Query<(&mut TextSpan, &mut TextColor), (With<NameAfterCaret>, Without<NameCaret>)>,
+) {
+    // Caret blinks at ~1Hz, solid right after an edit
+    let since_edit = time.elapsed_secs() - name.last_edit;
+    let caret_on = ((since_edit * 2.0) as u32).is_multiple_of(2);
+    for mut c in &mut q_caret {
+        c.0 = Color::srgba(0.20, 0.90, 0.95, if caret_on { 1.0 } else { 0.0 });
+    }
+
+    if !name.is_changed() {
+        return;
+    }
 
-        for mut t in &mut q_name {
-            *t = Text::new(shown.clone());
+    let (before, after) = name.field.split_at_caret();
+    let placeholder = name.field.text().is_empty();
+
+    for mut t in &mut q_before {
+        *t = Text::new(before);
+    }
+    for (mut span, mut color) in &mut q_after {
+        if placeholder {
+            **span = NAME_PLACEHOLDER.to_string();
+            color.0 = Color::srgb(0.50, 0.50, 0.55);
+        } else {
+            **span = after.to_string();
+            color.0 = Color::srgb(0.93, 0.93, 0.96);
         }
     }
 }
@@ -417,6 +929,11 @@ fn menu_buttons(
             Option<&StartButton>,
             Option<&QuitButton>,
             Option<&DifficultyButton>,
+            Option<&ControlsButton>,
+            Option<&SettingsButton>,
+            Option<&ControlSchemeButton>,
+            Option<&MazeSourceButton>,
+            Option<&FeverSpikesButton>,
         ),
         (With<Button>, Changed<Interaction>),
     >,
@@ -424,21 +941,41 @@ fn menu_buttons(
     mut next_state: ResMut<NextState<GameState>>,
     mut exit: MessageWriter<AppExit>,
 ) {
-    for (i, start, quit, diff) in &mut q {
+    for (i, start, quit, diff, controls, settings, scheme, maze, fever) in &mut q {
         if *i != Interaction::Pressed {
             continue;
         }
 
         if let Some(d) = diff {
             config.difficulty = d.0;
+        } else if let Some(c) = scheme {
+            config.control_scheme = c.0;
+        } else if let Some(m) = maze {
+            config.maze = m.0;
+        } else if let Some(f) = fever {
+            config.fever_spikes = f.0;
         } else if start.is_some() {
-            next_state.set(GameState::Countdown);
+            next_state.set(start_state(&config));
         } else if quit.is_some() {
             exit.write(AppExit::Success);
+        } else if controls.is_some() {
+            next_state.set(GameState::Controls);
+        } else if settings.is_some() {
+            next_state.set(GameState::Settings);
         }
     }
 }
 
+#[cfg(feature = "dev")]
+fn editor_button(
+    q: Query<&Interaction, (With<EditorButton>, Changed<Interaction>)>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if q.iter().any(|i| *i == Interaction::Pressed) {
+        next_state.set(GameState::Editor);
+    }
+}
+
 fn menu_visuals(
     time: Res<Time>,
     config: Res<GameConfig>,
@@ -450,6 +987,9 @@ fn menu_visuals(
             Option<&StartButton>,
             Option<&QuitButton>,
             Option<&DifficultyButton>,
+            Option<&ControlSchemeButton>,
+            Option<&MazeSourceButton>,
+            Option<&FeverSpikesButton>,
         ),
         With<Button>,
     >,
@@ -499,7 +1039,7 @@ fn menu_visuals(
     let hovered_bg = Color::srgb(0.20, 0.20, 0.24);
     let pressed_bg = Color::srgb(0.24, 0.24, 0.30);
 
-    for (i, mut bg, mut border, start, quit, diff) in &mut q_buttons {
+    for (i, mut bg, mut border, start, quit, diff, scheme, maze, fever) in &mut q_buttons {
         // Defaults
         let mut base_bg = Color::srgb(0.14, 0.14, 0.16);
         let mut base_border = dim_border;
@@ -524,6 +1064,18 @@ fn menu_visuals(
                 base_bg = Color::srgb(0.13, 0.13, 0.15);
                 base_border = dim_border;
             }
+        } else if let Some(selected) = scheme
+            .map(|c| c.0 == config.control_scheme)
+            .or(maze.map(|m| m.0 == config.maze))
+            .or(fever.map(|f| f.0 == config.fever_spikes))
+        {
+            if selected {
+                base_bg = Color::srgb(0.16, 0.16, 0.20);
+                base_border = cyan;
+            } else {
+                base_bg = Color::srgb(0.13, 0.13, 0.15);
+                base_border = dim_border;
+            }
         }
 
         match *i {
@@ -544,55 +1096,12 @@ fn menu_visuals(
 }
 
 fn cleanup_menu(mut commands: Commands, q: Query<Entity, With<MenuTag>>) {
+    clipboard::set_focused(false);
     for e in &q {
         commands.entity(e).despawn();
     }
 }
 
-fn keycode_to_char(key: KeyCode, shift: bool) -> Option<char> {
-    let c = match key {
-        KeyCode::KeyA => 'a',
-        KeyCode::KeyB => 'b',
-        KeyCode::KeyC => 'c',
-        KeyCode::KeyD => 'd',
-        KeyCode::KeyE => 'e',
-        KeyCode::KeyF => 'f',
-        KeyCode::KeyG => 'g',
-        KeyCode::KeyH => 'h',
-        KeyCode::KeyI => 'i',
-        KeyCode::KeyJ => 'j',
-        KeyCode::KeyK => 'k',
-        KeyCode::KeyL => 'l',
-        KeyCode::KeyM => 'm',
-        KeyCode::KeyN => 'n',
-        KeyCode::KeyO => 'o',
-        KeyCode::KeyP => 'p',
-        KeyCode::KeyQ => 'q',
-        KeyCode::KeyR => 'r',
-        KeyCode::KeyS => 's',
-        KeyCode::KeyT => 't',
-        KeyCode::KeyU => 'u',
-        KeyCode::KeyV => 'v',
-        KeyCode::KeyW => 'w',
-        KeyCode::KeyX => 'x',
-        KeyCode::KeyY => 'y',
-        KeyCode::KeyZ => 'z',
-        KeyCode::Digit0 => '0',
-        KeyCode::Digit1 => '1',
-        KeyCode::Digit2 => '2',
-        KeyCode::Digit3 => '3',
-        KeyCode::Digit4 => '4',
-        KeyCode::Digit5 => '5',
-        KeyCode::Digit6 => '6',
-        KeyCode::Digit7 => '7',
-        KeyCode::Digit8 => '8',
-        KeyCode::Digit9 => '9',
-        _ => return None,
-    };
-
-    Some(if shift { c.to_ascii_uppercase() } else { c })
-}
-
 fn lerp(a: f32, b: f32, t: f32) -> f32 {
     a + (b - a) * t
 }
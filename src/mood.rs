@@ -0,0 +1,364 @@
+//! Moods: how the fever bends the controls and the physics.
+//!
+//! Moods live in `assets/moods/*.moods.ron` and are read by [`MoodLoader`], so a
+//! new kind of fever only needs a new entry in the file. The first
+//! [`MOOD_SLOTS`] moods get a number key each, every mood is reachable with
+//! next / previous mood.
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::loading::MoodAssets;
+
+pub struct MoodPlugin;
+
+impl Plugin for MoodPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<MoodSet>()
+            .init_asset_loader::<MoodLoader>()
+            .init_resource::<Mood>()
+            .init_resource::<MoodEnergy>();
+    }
+}
+
+/// Moods that can have a key of their own (`GameControl::Mood`)
+pub const MOOD_SLOTS: u8 = 9;
+
+/// The player's current mood, an index into the loaded [`MoodSet`]
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Mood(pub usize);
+
+impl Mood {
+    pub fn next(self, count: usize) -> Self {
+        Mood((self.0 + 1) % count.max(1))
+    }
+
+    pub fn prev(self, count: usize) -> Self {
+        let count = count.max(1);
+        Mood((self.0 + count - 1) % count)
+    }
+}
+
+/// Size of the mood energy meter
+pub const MAX_ENERGY: f32 = 100.0;
+
+/// Switching moods costs energy; the mood the player is in refills or drains it.
+/// Reset to full when a run starts.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct MoodEnergy {
+    /// 0..`MAX_ENERGY`
+    pub current: f32,
+    /// Seconds until the next switch is allowed
+    pub cooldown: f32,
+    /// Seconds left on the "can't switch" flash in the HUD
+    pub denied: f32,
+}
+
+impl Default for MoodEnergy {
+    fn default() -> Self {
+        Self {
+            current: MAX_ENERGY,
+            cooldown: 0.0,
+            denied: 0.0,
+        }
+    }
+}
+
+#[derive(Asset, TypePath, Deserialize, Debug, Clone, PartialEq)]
+pub struct MoodSet {
+    /// In key / cycle order; the first one is the mood every run starts in
+    pub moods: Vec<MoodDef>,
+}
+
+/// One mood. With input held the player settles at `acceleration / drag`,
+/// without input gravity drifts it at `gravity / drag`.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct MoodDef {
+    pub name: String,
+    /// sRGB 0..1; tints the player and the HUD
+    color: (f32, f32, f32),
+    /// Push from full input, units/s²
+    pub acceleration: f32,
+    /// Hard cap on the speed, units/s
+    pub max_speed: f32,
+    /// Fraction of the velocity lost per second (exponential)
+    pub drag: f32,
+    /// Accumulates into the velocity every frame, units/s²
+    pub gravity: Vec2,
+    #[serde(default)]
+    pub input: InputTransform,
+    #[serde(default)]
+    pub wobble: Option<Wobble>,
+    #[serde(default)]
+    pub drift: Option<Drift>,
+    #[serde(default)]
+    pub music: MoodMusic,
+    /// Energy per second while in this mood; negative drains the meter
+    #[serde(default = "default_energy_rate")]
+    pub energy_rate: f32,
+    /// Energy it takes to switch into this mood
+    #[serde(default = "default_switch_cost")]
+    pub switch_cost: f32,
+}
+
+fn default_energy_rate() -> f32 {
+    5.0
+}
+
+fn default_switch_cost() -> f32 {
+    25.0
+}
+
+/// What the mood does to the stick / keys before they push the player
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(default)]
+pub struct InputTransform {
+    /// Degrees, counter-clockwise (-90 = up pushes right)
+    pub rotate: f32,
+    /// Left and right swapped
+    pub mirror_x: bool,
+    /// Up and down swapped
+    pub mirror_y: bool,
+    /// Every direction reversed
+    pub invert: bool,
+}
+
+/// The controls sway back and forth by up to `degrees`, `frequency` times a second
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Wobble {
+    pub degrees: f32,
+    pub frequency: f32,
+}
+
+/// An extra push (units/s²) whose direction turns `turn_rate` degrees a second
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Drift {
+    pub strength: f32,
+    pub turn_rate: f32,
+}
+
+/// How the music loop is played in this mood
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct MoodMusic {
+    pub playback_rate: f64,
+    /// -1 = left, 0 = centre, 1 = right
+    pub panning: f32,
+}
+
+impl Default for MoodMusic {
+    fn default() -> Self {
+        Self {
+            playback_rate: 1.0,
+            panning: 0.0,
+        }
+    }
+}
+
+impl MoodDef {
+    pub fn color(&self) -> Color {
+        let (r, g, b) = self.color;
+        Color::srgb(r, g, b)
+    }
+
+    /// Input direction after the mood has had its way, `t` seconds into the run
+    pub fn steer(&self, input: Vec2, t: f32) -> Vec2 {
+        let mut v = input;
+        if self.input.invert {
+            v = -v;
+        }
+        if self.input.mirror_x {
+            v.x = -v.x;
+        }
+        if self.input.mirror_y {
+            v.y = -v.y;
+        }
+
+        let sway = self.wobble.map_or(0.0, |w| {
+            w.degrees * (t * w.frequency * std::f32::consts::TAU).sin()
+        });
+        Vec2::from_angle((self.input.rotate + sway).to_radians()).rotate(v)
+    }
+
+    /// Everything pulling on the player without input: gravity plus drift
+    pub fn pull(&self, t: f32) -> Vec2 {
+        let drift = self.drift.map_or(Vec2::ZERO, |d| {
+            Vec2::from_angle((d.turn_rate * t).to_radians()) * d.strength
+        });
+        self.gravity + drift
+    }
+}
+
+/// Read access to the loaded moods. Empty until the mood file has loaded.
+#[derive(SystemParam)]
+pub struct Moods<'w> {
+    assets: Option<Res<'w, MoodAssets>>,
+    sets: Res<'w, Assets<MoodSet>>,
+}
+
+impl Moods<'_> {
+    pub fn all(&self) -> &[MoodDef] {
+        self.assets
+            .as_ref()
+            .and_then(|a| self.sets.get(&a.set))
+            .map_or(&[], |s| s.moods.as_slice())
+    }
+
+    pub fn get(&self, mood: Mood) -> Option<&MoodDef> {
+        self.all().get(mood.0)
+    }
+
+    /// Name for HUD / menus, `?` for a mood the file no longer has
+    pub fn name(&self, mood: Mood) -> &str {
+        self.get(mood).map_or("?", |m| m.name.as_str())
+    }
+}
+
+/// Problems that make a mood file unusable
+pub fn validate(set: &MoodSet) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+    if set.moods.is_empty() {
+        errors.push("no moods defined".to_string());
+    }
+    // Burning out drops the player back into the first mood, so it has to recover
+    if set.moods.first().is_some_and(|m| m.energy_rate < 0.0) {
+        errors.push("the first mood can't drain energy".to_string());
+    }
+
+    for (i, m) in set.moods.iter().enumerate() {
+        let name = &m.name;
+        if name.trim().is_empty() {
+            errors.push(format!("mood #{} has no name", i + 1));
+        }
+        if set.moods[..i].iter().any(|other| other.name == *name) {
+            errors.push(format!("'{name}' is defined twice"));
+        }
+        if m.max_speed <= 0.0 {
+            errors.push(format!("'{name}': max_speed must be above 0"));
+        }
+        if m.acceleration < 0.0 || m.drag < 0.0 {
+            errors.push(format!("'{name}': acceleration and drag can't be negative"));
+        }
+        let (r, g, b) = m.color;
+        if [r, g, b].iter().any(|c| !(0.0..=1.0).contains(c)) {
+            errors.push(format!("'{name}': color channels go from 0 to 1"));
+        }
+        if m.wobble.is_some_and(|w| w.frequency < 0.0) {
+            errors.push(format!("'{name}': wobble frequency can't be negative"));
+        }
+        if !(0.0..=MAX_ENERGY).contains(&m.switch_cost) {
+            errors.push(format!("'{name}': switch_cost goes from 0 to {MAX_ENERGY}"));
+        }
+        if m.music.playback_rate <= 0.0 {
+            errors.push(format!("'{name}': music playback_rate must be above 0"));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[derive(Default, TypePath)]
+pub struct MoodLoader;
+
+#[derive(Debug)]
+pub enum MoodLoadError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+    Invalid(Vec<String>),
+}
+
+impl std::fmt::Display for MoodLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MoodLoadError::Io(e) => write!(f, "could not read mood file: {e}"),
+            MoodLoadError::Ron(e) => write!(f, "invalid mood file: {e}"),
+            MoodLoadError::Invalid(errors) => {
+                write!(f, "broken mood definitions")?;
+                for e in errors {
+                    write!(f, "\n  - {e}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for MoodLoadError {}
+
+impl AssetLoader for MoodLoader {
+    type Asset = MoodSet;
+    type Settings = ();
+    type Error = MoodLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<MoodSet, MoodLoadError> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(MoodLoadError::Io)?;
+        let set: MoodSet = ron::de::from_bytes(&bytes).map_err(MoodLoadError::Ron)?;
+        validate(&set).map_err(MoodLoadError::Invalid)?;
+        Ok(set)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["moods.ron"]
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn fever_moods() -> MoodSet {
+        ron::from_str(include_str!("../assets/moods/fever.moods.ron")).unwrap()
+    }
+
+    fn named(name: &str) -> MoodDef {
+        fever_moods()
+            .moods
+            .into_iter()
+            .find(|m| m.name == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn built_in_moods_are_valid() {
+        assert_eq!(validate(&fever_moods()), Ok(()));
+    }
+
+    #[test]
+    fn input_transforms() {
+        let up = Vec2::Y;
+        // A quarter turn clockwise: up pushes right
+        assert!(named("Sideways").steer(up, 0.0).abs_diff_eq(Vec2::X, 1e-5));
+        assert_eq!(named("Normal").steer(up, 0.0), up);
+
+        let mut def = named("Normal");
+        def.input.mirror_x = true;
+        assert_eq!(def.steer(Vec2::new(1.0, 1.0), 0.0), Vec2::new(-1.0, 1.0));
+        def.input.invert = true;
+        assert_eq!(def.steer(Vec2::new(1.0, 1.0), 0.0), Vec2::new(1.0, -1.0));
+    }
+
+    #[test]
+    fn broken_moods_are_rejected() {
+        let mut set = fever_moods();
+        set.moods[1].name = set.moods[0].name.clone();
+        set.moods[2].max_speed = 0.0;
+        assert_eq!(validate(&set).unwrap_err().len(), 2);
+        assert!(validate(&MoodSet { moods: Vec::new() }).is_err());
+    }
+}
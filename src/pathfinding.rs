@@ -0,0 +1,330 @@
+//! Grid navigation for an axis-aligned agent inside the arena.
+//!
+//! The arena is rasterised into square cells; a cell is free when the agent's
+//! box centred on it fits between the walls and inside the clamp margin.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+use bevy::math::{Rect, Vec2};
+
+pub type Cell = (usize, usize);
+
+pub struct NavGrid {
+    /// World position of the centre of cell (0, 0)
+    origin: Vec2,
+    cell: f32,
+    cols: usize,
+    rows: usize,
+    blocked: Vec<bool>,
+}
+
+impl NavGrid {
+    /// `half_extents`: arena half size, `margin`: how close the agent centre may get to the border (per axis),
+    /// `agent_half`: half size of the agent box, `cell`: grid resolution in world units.
+    pub fn build(
+        half_extents: Vec2,
+        margin: Vec2,
+        walls: &[Rect],
+        agent_half: Vec2,
+        cell: f32,
+    ) -> Self {
+        let min = -half_extents + margin;
+        let max = half_extents - margin;
+        let span = (max - min).max(Vec2::ZERO);
+        let cols = (span.x / cell).floor() as usize + 1;
+        let rows = (span.y / cell).floor() as usize + 1;
+        // Centre the lattice inside the allowed area
+        let used = Vec2::new((cols - 1) as f32, (rows - 1) as f32) * cell;
+        let origin = min + (span - used) * 0.5;
+
+        let mut grid = Self {
+            origin,
+            cell,
+            cols,
+            rows,
+            blocked: vec![false; cols * rows],
+        };
+
+        for row in 0..rows {
+            for col in 0..cols {
+                let agent = Rect::from_center_half_size(grid.center((col, row)), agent_half);
+                grid.blocked[row * cols + col] = walls.iter().any(|w| overlaps(agent, *w));
+            }
+        }
+
+        grid
+    }
+
+    pub fn center(&self, (col, row): Cell) -> Vec2 {
+        self.origin + Vec2::new(col as f32, row as f32) * self.cell
+    }
+
+    /// Nearest lattice cell to a world position (clamped into the grid)
+    pub fn cell_of(&self, p: Vec2) -> Cell {
+        let local = ((p - self.origin) / self.cell).round();
+        (
+            (local.x.max(0.0) as usize).min(self.cols - 1),
+            (local.y.max(0.0) as usize).min(self.rows - 1),
+        )
+    }
+
+    pub fn is_free(&self, (col, row): Cell) -> bool {
+        col < self.cols && row < self.rows && !self.blocked[row * self.cols + col]
+    }
+
+    /// Closest free cell to `from` (breadth-first), if any exists
+    pub fn nearest_free(&self, from: Cell) -> Option<Cell> {
+        if self.is_free(from) {
+            return Some(from);
+        }
+
+        let mut seen = vec![false; self.cols * self.rows];
+        let mut queue = VecDeque::from([from]);
+        seen[self.index(from)] = true;
+
+        while let Some(c) = queue.pop_front() {
+            if self.is_free(c) {
+                return Some(c);
+            }
+            for (n, _) in self.neighbours(c, false) {
+                let i = self.index(n);
+                if !seen[i] {
+                    seen[i] = true;
+                    queue.push_back(n);
+                }
+            }
+        }
+        None
+    }
+
+    /// Flood fill: which cells the agent can reach from `start` (indexed `row * cols + col`)
+    pub fn reachable_from(&self, start: Cell) -> Vec<bool> {
+        let mut reached = vec![false; self.cols * self.rows];
+        if !self.is_free(start) {
+            return reached;
+        }
+
+        let mut queue = VecDeque::from([start]);
+        reached[self.index(start)] = true;
+        while let Some(c) = queue.pop_front() {
+            for (n, _) in self.neighbours(c, true) {
+                let i = self.index(n);
+                if !reached[i] {
+                    reached[i] = true;
+                    queue.push_back(n);
+                }
+            }
+        }
+        reached
+    }
+
+    pub fn is_reachable(&self, reached: &[bool], c: Cell) -> bool {
+        reached.get(self.index(c)).copied().unwrap_or(false)
+    }
+
+    /// Some reached cell lies within `radius` of world position `p`
+    pub fn reachable_near(&self, reached: &[bool], p: Vec2, radius: f32) -> bool {
+        let (col, row) = self.cell_of(p);
+        let span = (radius / self.cell).ceil() as usize + 1;
+        let cols = col.saturating_sub(span)..=(col + span).min(self.cols - 1);
+        let rows = row.saturating_sub(span)..=(row + span).min(self.rows - 1);
+        rows.flat_map(|r| cols.clone().map(move |c| (c, r)))
+            .any(|c| self.is_reachable(reached, c) && self.center(c).distance(p) <= radius)
+    }
+
+    /// A* from `from` to `to` (world positions). Returns smoothed waypoints, ending at the goal cell.
+    pub fn find_path(&self, from: Vec2, to: Vec2) -> Option<Vec<Vec2>> {
+        let start = self.nearest_free(self.cell_of(from))?;
+        let goal = self.nearest_free(self.cell_of(to))?;
+
+        let n = self.cols * self.rows;
+        let mut cost = vec![u32::MAX; n];
+        let mut came_from: Vec<Option<Cell>> = vec![None; n];
+        let mut open = BinaryHeap::new();
+
+        cost[self.index(start)] = 0;
+        open.push(Reverse((self.heuristic(start, goal), 0u32, start)));
+
+        while let Some(Reverse((_, g, c))) = open.pop() {
+            if c == goal {
+                break;
+            }
+            if g > cost[self.index(c)] {
+                continue;
+            }
+            for (nb, step) in self.neighbours(c, true) {
+                let ng = g + step;
+                let i = self.index(nb);
+                if ng < cost[i] {
+                    cost[i] = ng;
+                    came_from[i] = Some(c);
+                    open.push(Reverse((ng + self.heuristic(nb, goal), ng, nb)));
+                }
+            }
+        }
+
+        if cost[self.index(goal)] == u32::MAX {
+            return None;
+        }
+
+        let mut cells = vec![goal];
+        let mut c = goal;
+        while let Some(prev) = came_from[self.index(c)] {
+            cells.push(prev);
+            c = prev;
+        }
+        cells.reverse();
+
+        Some(self.smooth(&cells))
+    }
+
+    /// Drop waypoints that can be skipped in a straight line
+    fn smooth(&self, cells: &[Cell]) -> Vec<Vec2> {
+        let mut out = Vec::new();
+        let mut i = 0;
+        while i + 1 < cells.len() {
+            let mut j = cells.len() - 1;
+            while j > i + 1 && !self.line_free(self.center(cells[i]), self.center(cells[j])) {
+                j -= 1;
+            }
+            out.push(self.center(cells[j]));
+            i = j;
+        }
+        if out.is_empty() {
+            out.push(self.center(cells[0]));
+        }
+        out
+    }
+
+    /// Every cell touched by the segment is free (sampled at quarter-cell steps)
+    pub fn line_free(&self, a: Vec2, b: Vec2) -> bool {
+        let steps = ((b - a).length() / (self.cell * 0.25)).ceil().max(1.0) as usize;
+        (0..=steps).all(|s| {
+            let p = a.lerp(b, s as f32 / steps as f32);
+            self.is_free(self.cell_of(p))
+        })
+    }
+
+    fn index(&self, (col, row): Cell) -> usize {
+        row * self.cols + col
+    }
+
+    fn heuristic(&self, (ac, ar): Cell, (bc, br): Cell) -> u32 {
+        // Octile distance, 10 per straight step / 14 per diagonal
+        let dx = ac.abs_diff(bc) as u32;
+        let dy = ar.abs_diff(br) as u32;
+        10 * dx.max(dy) + 4 * dx.min(dy)
+    }
+
+    /// Free neighbours and their step cost. Diagonals never cut a blocked corner.
+    fn neighbours(&self, (col, row): Cell, free_only: bool) -> Vec<(Cell, u32)> {
+        let mut out = Vec::with_capacity(8);
+        for dy in -1i32..=1 {
+            for dx in -1i32..=1 {
+                if dx == 0 && dy == 0 {
+                    continue;
+                }
+                let nc = col as i32 + dx;
+                let nr = row as i32 + dy;
+                if nc < 0 || nr < 0 || nc >= self.cols as i32 || nr >= self.rows as i32 {
+                    continue;
+                }
+                let n = (nc as usize, nr as usize);
+                let diagonal = dx != 0 && dy != 0;
+                if free_only {
+                    if !self.is_free(n) {
+                        continue;
+                    }
+                    if diagonal
+                        && (!self.is_free((nc as usize, row)) || !self.is_free((col, nr as usize)))
+                    {
+                        continue;
+                    }
+                }
+                out.push((n, if diagonal { 14 } else { 10 }));
+            }
+        }
+        out
+    }
+}
+
+/// Strict overlap: touching edges do not count
+fn overlaps(a: Rect, b: Rect) -> bool {
+    a.min.x < b.max.x && a.max.x > b.min.x && a.min.y < b.max.y && a.max.y > b.min.y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CELL: f32 = 10.0;
+
+    /// 200x200 arena split by a wall down the middle with a gap at the top
+    fn split_arena(extra: &[Rect]) -> NavGrid {
+        let mut walls = vec![Rect::from_center_size(
+            Vec2::new(0.0, -20.0),
+            Vec2::new(10.0, 160.0),
+        )];
+        walls.extend_from_slice(extra);
+        NavGrid::build(
+            Vec2::splat(100.0),
+            Vec2::ZERO,
+            &walls,
+            Vec2::splat(5.0),
+            CELL,
+        )
+    }
+
+    fn assert_walkable(grid: &NavGrid, from: Vec2, path: &[Vec2]) {
+        let mut at = grid.center(grid.nearest_free(grid.cell_of(from)).unwrap());
+        for &p in path {
+            assert!(grid.line_free(at, p), "{at} -> {p} crosses a wall");
+            at = p;
+        }
+    }
+
+    #[test]
+    fn path_goes_around_a_wall() {
+        let grid = split_arena(&[]);
+        let (from, to) = (Vec2::new(-50.0, -50.0), Vec2::new(50.0, -50.0));
+        assert!(!grid.line_free(from, to));
+
+        let path = grid.find_path(from, to).unwrap();
+        assert_eq!(path.last(), Some(&to));
+        assert!(
+            path.iter().any(|p| p.y > 60.0),
+            "{path:?} never uses the gap"
+        );
+        assert_walkable(&grid, from, &path);
+    }
+
+    #[test]
+    fn sealed_goal_has_no_path() {
+        // Closes off the top-right corner
+        let grid = split_arena(&[
+            Rect::from_center_size(Vec2::new(40.0, 60.0), Vec2::new(10.0, 80.0)),
+            Rect::from_center_size(Vec2::new(70.0, 20.0), Vec2::new(60.0, 10.0)),
+        ]);
+        let goal = Vec2::new(70.0, 70.0);
+        assert!(grid.is_free(grid.cell_of(goal)));
+        assert_eq!(grid.find_path(Vec2::new(-50.0, -50.0), goal), None);
+    }
+
+    #[test]
+    fn start_inside_a_wall_snaps_out() {
+        let grid = split_arena(&[]);
+        let from = Vec2::new(0.0, -50.0);
+        assert!(!grid.is_free(grid.cell_of(from)));
+
+        // One step out of the wall, diagonals included
+        let (col, row) = grid.cell_of(from);
+        let snapped = grid.nearest_free((col, row)).unwrap();
+        assert_eq!(snapped.0.abs_diff(col).max(snapped.1.abs_diff(row)), 1);
+
+        let to = Vec2::new(-50.0, -50.0);
+        let path = grid.find_path(from, to).unwrap();
+        assert_eq!(path.last(), Some(&to));
+        assert_walkable(&grid, from, &path);
+    }
+}
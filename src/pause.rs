@@ -0,0 +1,246 @@
+//! Pausing a run: overlay, frozen clock and auto-pause.
+//!
+//! Gameplay systems only run in `PauseState::Running`, and `Time<Virtual>` is
+//! paused whenever the run is not running, so the countdown, the game timer
+//! and the mood physics all stand still until the player resumes.
+
+use bevy::prelude::*;
+use bevy::window::{AppLifecycle, WindowFocused};
+
+use crate::actions::{Actions, GameControl, InputBindings};
+use crate::{GameState, PauseState};
+
+pub struct PausePlugin;
+
+#[derive(Component)]
+struct PauseEntity;
+
+#[derive(Component, Clone, Copy)]
+enum PauseButton {
+    Resume,
+    Restart,
+    Settings,
+    Menu,
+}
+
+impl PauseButton {
+    const ALL: [PauseButton; 4] = [
+        PauseButton::Resume,
+        PauseButton::Restart,
+        PauseButton::Settings,
+        PauseButton::Menu,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            PauseButton::Resume => "Resume",
+            PauseButton::Restart => "Restart",
+            PauseButton::Settings => "Settings",
+            PauseButton::Menu => "Quit to Menu",
+        }
+    }
+}
+
+impl Plugin for PausePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, sync_virtual_time)
+            .add_systems(
+                Update,
+                (pause_input, auto_pause).run_if(in_state(PauseState::Running)),
+            )
+            .add_systems(OnEnter(PauseState::Paused), setup_pause_overlay)
+            .add_systems(
+                Update,
+                (paused_input, pause_buttons, pause_visuals)
+                    .chain()
+                    .run_if(in_state(PauseState::Paused)),
+            )
+            .add_systems(OnExit(PauseState::Paused), cleanup_pause_overlay);
+    }
+}
+
+/// Virtual time only runs while a run is running (or outside of a run)
+fn sync_virtual_time(pause: Option<Res<State<PauseState>>>, mut time: ResMut<Time<Virtual>>) {
+    let paused = pause.is_some_and(|p| *p.get() != PauseState::Running);
+    if paused != time.is_paused() {
+        if paused {
+            time.pause();
+        } else {
+            time.unpause();
+        }
+    }
+}
+
+fn pause_input(actions: Res<Actions>, mut next_pause: ResMut<NextState<PauseState>>) {
+    if actions.pause || actions.cancel {
+        next_pause.set(PauseState::Paused);
+    }
+}
+
+/// Pause when the window loses focus or the mobile app goes to the background
+fn auto_pause(
+    mut focus: MessageReader<WindowFocused>,
+    mut lifecycle: MessageReader<AppLifecycle>,
+    mut next_pause: ResMut<NextState<PauseState>>,
+) {
+    let lost_focus = focus.read().any(|f| !f.focused);
+    let backgrounded = lifecycle
+        .read()
+        .any(|l| matches!(l, AppLifecycle::WillSuspend | AppLifecycle::Suspended));
+    if lost_focus || backgrounded {
+        next_pause.set(PauseState::Paused);
+    }
+}
+
+fn setup_pause_overlay(mut commands: Commands, bindings: Res<InputBindings>) {
+    commands
+        .spawn((
+            PauseEntity,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(0.0),
+                left: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.65)),
+        ))
+        .with_children(|ui| {
+            ui.spawn((
+                PauseEntity,
+                Node {
+                    width: Val::Px(380.0),
+                    padding: UiRect::all(Val::Px(24.0)),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(12.0),
+                    align_items: AlignItems::Center,
+                    border_radius: BorderRadius::all(Val::Px(16.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.10, 0.10, 0.12)),
+            ))
+            .with_children(|card| {
+                card.spawn((
+                    PauseEntity,
+                    Text::new("PAUSED"),
+                    TextFont {
+                        font_size: 44.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.95, 0.95, 0.98)),
+                ));
+
+                for button in PauseButton::ALL {
+                    card.spawn((
+                        PauseEntity,
+                        Button,
+                        button,
+                        BackgroundColor(Color::srgb(0.18, 0.18, 0.20)),
+                        Node {
+                            width: Val::Px(240.0),
+                            height: Val::Px(50.0),
+                            align_items: AlignItems::Center,
+                            justify_content: JustifyContent::Center,
+                            border_radius: BorderRadius::all(Val::Px(12.0)),
+                            ..default()
+                        },
+                    ))
+                    .with_child((
+                        PauseEntity,
+                        Text::new(button.label()),
+                        TextFont {
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                }
+
+                card.spawn((
+                    PauseEntity,
+                    Text::new(format!(
+                        "Keys: {}/{} = Resume    {} = Restart    {} = Menu",
+                        bindings.primary_label(GameControl::Pause),
+                        bindings.primary_label(GameControl::Cancel),
+                        bindings.primary_label(GameControl::Replay),
+                        bindings.primary_label(GameControl::BackToMenu),
+                    )),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.75, 0.75, 0.78)),
+                ));
+            });
+        });
+}
+
+fn apply_choice(
+    button: PauseButton,
+    next_state: &mut NextState<GameState>,
+    next_pause: &mut NextState<PauseState>,
+) {
+    match button {
+        PauseButton::Resume => next_pause.set(PauseState::Running),
+        PauseButton::Restart => {
+            // Same level (and seed) from the countdown; the countdown clears the old world
+            next_state.set(GameState::Countdown);
+            next_pause.set(PauseState::Running);
+        }
+        PauseButton::Settings => next_pause.set(PauseState::Settings),
+        PauseButton::Menu => next_state.set(GameState::Menu),
+    }
+}
+
+fn paused_input(
+    actions: Res<Actions>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut next_pause: ResMut<NextState<PauseState>>,
+) {
+    let choice = if actions.pause || actions.cancel || actions.confirm {
+        Some(PauseButton::Resume)
+    } else if actions.replay {
+        Some(PauseButton::Restart)
+    } else if actions.back_to_menu {
+        Some(PauseButton::Menu)
+    } else {
+        None
+    };
+
+    if let Some(choice) = choice {
+        apply_choice(choice, &mut next_state, &mut next_pause);
+    }
+}
+
+fn pause_buttons(
+    q: Query<(&Interaction, &PauseButton), Changed<Interaction>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut next_pause: ResMut<NextState<PauseState>>,
+) {
+    for (i, button) in &q {
+        if *i == Interaction::Pressed {
+            apply_choice(*button, &mut next_state, &mut next_pause);
+        }
+    }
+}
+
+fn pause_visuals(
+    mut q: Query<(&Interaction, &mut BackgroundColor), (With<Button>, With<PauseButton>)>,
+) {
+    for (interaction, mut bg) in &mut q {
+        *bg = BackgroundColor(match *interaction {
+            Interaction::Pressed => Color::srgb(0.28, 0.28, 0.31),
+            Interaction::Hovered => Color::srgb(0.24, 0.24, 0.26),
+            Interaction::None => Color::srgb(0.18, 0.18, 0.20),
+        });
+    }
+}
+
+fn cleanup_pause_overlay(mut commands: Commands, q: Query<Entity, With<PauseEntity>>) {
+    for e in &q {
+        commands.entity(e).despawn();
+    }
+}
@@ -1,5 +1,12 @@
-use crate::actions::Actions;
-use crate::{Difficulty, GameConfig, GameState};
+use crate::actions::{Actions, GameControl, InputBindings, MoodIntent};
+use crate::audio::SoundEffect;
+use crate::campaign::next_level;
+use crate::collision::{self, Collider, Layers, Overlap, stop_against};
+use crate::highscores::{RunRank, record_high_score};
+use crate::level::{CurrentLevel, LevelData, reroll_seed, select_level};
+use crate::loading::LevelAssets;
+use crate::mood::{MAX_ENERGY, MOOD_SLOTS, Mood, MoodEnergy, Moods};
+use crate::{Difficulty, GameConfig, GameState, PauseState};
 
 use bevy::app::AppExit;
 use bevy::ecs::message::MessageWriter;
@@ -9,11 +16,31 @@ use bevy::prelude::*;
 /// Gameplay + countdown + game over
 pub struct PlayerPlugin;
 
+/// Player sprite edge length (art only)
+pub(crate) const PLAYER_SIZE: f32 = 44.0;
+/// Half size of the player's collider; walls, the arena border and the nav grid use it
+pub(crate) const PLAYER_HALF_EXTENTS: Vec2 = Vec2::splat(22.0);
+/// Memory sprite edge length (art only)
+pub(crate) const MEMORY_SIZE: f32 = 22.0;
+/// Memory pickup collider, a little inside the art so a pickup needs a real touch
+pub(crate) const MEMORY_RADIUS: f32 = 8.0;
+/// Movement / collision steps per second
+const PHYSICS_HZ: f64 = 120.0;
+/// Seconds after a mood switch before the next one is allowed
+const SWITCH_COOLDOWN: f32 = 0.5;
+/// How long the energy bar flashes when a switch is refused
+const DENIED_FLASH: f32 = 0.35;
+
 #[derive(Component)]
 pub struct Player;
 
+/// World units per second. Moved by `move_player`, trimmed by walls and the arena border.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Velocity(pub Vec2);
+
+/// Arena half extents live in this entity's translation (x = half width, y = half height)
 #[derive(Component)]
-struct WorldBounds;
+pub(crate) struct WorldBounds;
 
 #[derive(Component)]
 struct BackToMenuButton;
@@ -21,15 +48,21 @@ struct BackToMenuButton;
 #[derive(Component)]
 struct DifficultyChoiceButton(Difficulty);
 
-#[derive(Component)]
+#[derive(Component, Clone)]
 struct PlayingEntity; // tag EVERYTHING spawned for Countdown/Playing
 
+/// Arena pieces built from the level file (rebuilt on hot reload)
+#[derive(Component, Clone)]
+struct LevelEntity;
+
 #[derive(Component)]
 struct GameOverEntity; // tag EVERYTHING spawned for GameOver overlay
 
 #[derive(Component)]
 struct HudMood;
 #[derive(Component)]
+struct HudEnergyFill;
+#[derive(Component)]
 struct HudScore;
 #[derive(Component)]
 struct HudTime;
@@ -37,32 +70,22 @@ struct HudTime;
 struct HurryText;
 
 #[derive(Component)]
-struct MazeWall;
+pub(crate) struct MazeWall;
 
 #[derive(Component)]
 struct CountdownText;
 
 #[derive(Component)]
 struct ReplayButton;
+/// Campaign index the button moves on to
+#[derive(Component)]
+struct NextLevelButton(usize);
 #[derive(Component)]
 struct QuitButton;
 
 #[derive(Component)]
 struct Memory;
 
-#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Mood {
-    Normal,
-    Heavy,
-    Sideways,
-}
-
-impl Default for Mood {
-    fn default() -> Self {
-        Mood::Normal
-    }
-}
-
 #[derive(Resource, Default)]
 struct Score(pub u32);
 
@@ -72,40 +95,124 @@ struct CountdownTimer(pub Timer);
 #[derive(Resource)]
 struct GameTimer(pub Timer);
 
+/// Numbers gathered while `Playing`, for balancing and the Game Over breakdown.
+/// Reset when a run starts playing.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct RunStats {
+    /// World units the player actually moved (after walls and clamping)
+    pub distance: f32,
+    /// Seconds spent in each mood, indexed like the mood file
+    mood_time: Vec<f32>,
+    pub mood_switches: u32,
+    /// Moods forced by the fever (not counted in `mood_switches`)
+    pub fever_spikes: u32,
+    /// Wall contacts resolved by `collide_with_maze` (a held contact counts once)
+    pub wall_collisions: u32,
+    /// Run time of every memory pickup, in order
+    pub pickups: Vec<f32>,
+    last_pos: Option<Vec2>,
+}
+
+impl RunStats {
+    pub fn time_in(&self, mood: Mood) -> f32 {
+        self.mood_time.get(mood.0).copied().unwrap_or(0.0)
+    }
+}
+
+/// Score points per whole second left on the clock after a victory
+const TIME_BONUS_PER_SECOND: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunResult {
+    /// Every memory collected before the timer ran out
+    Victory,
+    TimeUp,
+}
+
+/// How the last run ended. Inserted as a resource when `Playing` hands over to
+/// `GameOver` and also sent as a [`RunEnded`] message.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct RunOutcome {
+    pub result: RunResult,
+    /// Seconds played (the clear time on a victory)
+    pub time: f32,
+    /// Seconds left on the clock
+    pub remaining: f32,
+    pub collected: u32,
+    pub missed: u32,
+    /// Points added to `Score` for the remaining time (0 unless it was a victory)
+    pub time_bonus: u32,
+    /// Final score, bonus included
+    pub score: u32,
+}
+
+impl RunOutcome {
+    pub fn is_victory(&self) -> bool {
+        self.result == RunResult::Victory
+    }
+}
+
+/// Sent once per run, the frame it ends
+#[derive(Message, Debug, Clone, Copy)]
+pub struct RunEnded(pub RunOutcome);
+
+/// Set once the timer drops into the HURRY UP zone
 #[derive(Resource, Default)]
-struct LowTimeAlerted(pub bool);
+pub(crate) struct LowTimeAlerted(pub bool);
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<Mood>()
+        app.insert_resource(Time::<Fixed>::from_hz(PHYSICS_HZ))
             .init_resource::<Score>()
+            .init_resource::<RunStats>()
+            .add_message::<RunEnded>()
+            .add_message::<Overlap>()
             .init_resource::<LowTimeAlerted>()
             // Enter states
-            .add_systems(OnEnter(GameState::Countdown), enter_countdown)
+            .add_systems(
+                OnEnter(GameState::Countdown),
+                (select_level, enter_countdown).chain(),
+            )
             .add_systems(OnEnter(GameState::Playing), enter_playing)
-            .add_systems(OnEnter(GameState::GameOver), setup_game_over)
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                (record_high_score, setup_game_over).chain(),
+            )
             // Countdown updates (ORDERED)
             .add_systems(
                 Update,
-                (tick_countdown, countdown_input_skip, update_hud_countdown)
+                (
+                    tick_countdown,
+                    countdown_input_skip,
+                    tint_player,
+                    update_hud_countdown,
+                )
                     .chain()
-                    .run_if(in_state(GameState::Countdown)),
+                    .run_if(in_state(GameState::Countdown).and(in_state(PauseState::Running))),
             )
             // Playing updates (ORDERED)
             .add_systems(
                 Update,
                 (
                     mood_input,
-                    move_player,
-                    collide_with_maze,
-                    clamp_player,
+                    mood_energy,
+                    tint_player,
+                    track_run_stats,
+                    collision::detect_overlaps,
                     collect_memories,
                     tick_game_timer,
                     update_hud_playing,
                     check_game_over,
                 )
                     .chain()
-                    .run_if(in_state(GameState::Playing)),
+                    .run_if(in_state(GameState::Playing).and(in_state(PauseState::Running))),
+            )
+            // Movement + collision on a fixed step, so a slow frame can't change the outcome
+            .add_systems(
+                FixedUpdate,
+                (move_player, collide_with_maze, clamp_player)
+                    .chain()
+                    .run_if(in_state(GameState::Playing).and(in_state(PauseState::Running))),
             )
             // Game over updates (ORDERED)
             .add_systems(
@@ -118,16 +225,32 @@ impl Plugin for PlayerPlugin {
             .add_systems(
                 OnExit(GameState::GameOver),
                 (cleanup_game_over, cleanup_play_world).chain(),
-            );
+            )
+            // Quitting a run from the pause overlay skips Game Over
+            .add_systems(OnEnter(GameState::Menu), cleanup_play_world);
+
+        #[cfg(feature = "dev")]
+        app.add_systems(
+            Update,
+            hot_reload_level
+                .run_if(in_state(GameState::Countdown).or(in_state(GameState::Playing))),
+        );
     }
 }
 
 /* ----------------------- MAZE HELPERS ----------------------- */
 
-fn spawn_wall(commands: &mut Commands, pos: Vec2, size: Vec2, color: Color) {
+pub(crate) fn spawn_wall(
+    commands: &mut Commands,
+    pos: Vec2,
+    size: Vec2,
+    color: Color,
+    tag: impl Bundle,
+) {
     commands.spawn((
-        PlayingEntity,
+        tag,
         MazeWall,
+        Collider::aabb(size, Layers::WALL, Layers::PLAYER),
         Sprite {
             color,
             custom_size: Some(size),
@@ -137,89 +260,14 @@ fn spawn_wall(commands: &mut Commands, pos: Vec2, size: Vec2, color: Color) {
     ));
 }
 
-fn spawn_maze_walls(commands: &mut Commands, half_w: f32, half_h: f32) {
-    let t = 20.0; // thickness
-    let c = Color::srgb(0.12, 0.12, 0.14);
-
-    // Simple maze-like segments with gaps (safe to tweak)
-    spawn_wall(
-        commands,
-        Vec2::new(-half_w + 180.0, 120.0),
-        Vec2::new(t, 240.0),
-        c,
-    );
-    spawn_wall(
-        commands,
-        Vec2::new(-half_w + 180.0, -150.0),
-        Vec2::new(t, 180.0),
-        c,
-    );
-
-    spawn_wall(
-        commands,
-        Vec2::new(half_w - 210.0, 40.0),
-        Vec2::new(t, 260.0),
-        c,
-    );
-    spawn_wall(
-        commands,
-        Vec2::new(half_w - 210.0, -220.0),
-        Vec2::new(t, 140.0),
-        c,
-    );
-
-    spawn_wall(
-        commands,
-        Vec2::new(-120.0, half_h - 120.0),
-        Vec2::new(360.0, t),
-        c,
-    );
-
-    spawn_wall(commands, Vec2::new(-260.0, 10.0), Vec2::new(260.0, t), c);
-    spawn_wall(commands, Vec2::new(180.0, 10.0), Vec2::new(260.0, t), c);
-
-    spawn_wall(
-        commands,
-        Vec2::new(-40.0, -half_h + 120.0),
-        Vec2::new(420.0, t),
-        c,
-    );
-
-    spawn_wall(commands, Vec2::new(0.0, 170.0), Vec2::new(220.0, t), c);
-    spawn_wall(commands, Vec2::new(60.0, -110.0), Vec2::new(180.0, t), c);
-
-    spawn_wall(commands, Vec2::new(40.0, 70.0), Vec2::new(t, 160.0), c);
-}
-
-/* ----------------------- ENTER COUNTDOWN ----------------------- */
-
-fn enter_countdown(
-    mut commands: Commands,
-    mut score: ResMut<Score>,
-    mut mood: ResMut<Mood>,
-    mut alerted: ResMut<LowTimeAlerted>,
-    q_play: Query<Entity, With<PlayingEntity>>,
-    q_over: Query<Entity, With<GameOverEntity>>,
-) {
-    // Safety cleanup
-    for e in &q_over {
-        commands.entity(e).despawn();
-    }
-    for e in &q_play {
-        commands.entity(e).despawn();
-    }
-
-    // Reset run data
-    score.0 = 0;
-    *mood = Mood::Normal;
-    alerted.0 = false;
-
-    // Build world
-    let half_w = 520.0;
-    let half_h = 300.0;
+/// Bounds, floor, border and maze walls for a level. `tag` goes on every entity
+/// (the editor builds its preview with the same code as a real run).
+pub(crate) fn spawn_level(commands: &mut Commands, level: &LevelData, tag: impl Bundle + Clone) {
+    let half_w = level.half_extents.x;
+    let half_h = level.half_extents.y;
 
     commands.spawn((
-        PlayingEntity,
+        tag.clone(),
         WorldBounds,
         Transform::from_xyz(half_w, half_h, 0.0),
         GlobalTransform::default(),
@@ -229,7 +277,7 @@ fn enter_countdown(
 
     // background
     commands.spawn((
-        PlayingEntity,
+        tag.clone(),
         Sprite {
             color: Color::srgb(0.18, 0.18, 0.20),
             custom_size: Some(Vec2::new(half_w * 2.0, half_h * 2.0)),
@@ -242,56 +290,118 @@ fn enter_countdown(
     let wall = 22.0;
     let wall_color = Color::srgb(0.10, 0.10, 0.11);
 
+    for (size, pos) in [
+        (
+            Vec2::new(half_w * 2.0 + wall * 2.0, wall),
+            Vec2::new(0.0, half_h + wall / 2.0),
+        ),
+        (
+            Vec2::new(half_w * 2.0 + wall * 2.0, wall),
+            Vec2::new(0.0, -half_h - wall / 2.0),
+        ),
+        (
+            Vec2::new(wall, half_h * 2.0),
+            Vec2::new(-half_w - wall / 2.0, 0.0),
+        ),
+        (
+            Vec2::new(wall, half_h * 2.0),
+            Vec2::new(half_w + wall / 2.0, 0.0),
+        ),
+    ] {
+        commands.spawn((
+            tag.clone(),
+            Sprite {
+                color: wall_color,
+                custom_size: Some(size),
+                ..default()
+            },
+            Transform::from_xyz(pos.x, pos.y, 0.5),
+        ));
+    }
+
+    // Maze walls
+    let maze_color = Color::srgb(0.12, 0.12, 0.14);
+    for w in &level.walls {
+        spawn_wall(commands, w.center, w.size, maze_color, tag.clone());
+    }
+}
+
+fn spawn_memories(commands: &mut Commands, level: &LevelData, difficulty: Difficulty) {
+    for p in level.memories_for(difficulty) {
+        spawn_memory(commands, p, PlayingEntity);
+    }
+}
+
+/// Blocked by walls, touches every sensor
+fn player_collider() -> Collider {
+    Collider::aabb(
+        PLAYER_HALF_EXTENTS * 2.0,
+        Layers::PLAYER,
+        Layers::WALL | Layers::SENSORS,
+    )
+}
+
+pub(crate) fn spawn_memory(commands: &mut Commands, pos: Vec2, tag: impl Bundle) {
     commands.spawn((
-        PlayingEntity,
-        Sprite {
-            color: wall_color,
-            custom_size: Some(Vec2::new(half_w * 2.0 + wall * 2.0, wall)),
-            ..default()
-        },
-        Transform::from_xyz(0.0, half_h + wall / 2.0, 0.5),
-    ));
-    commands.spawn((
-        PlayingEntity,
-        Sprite {
-            color: wall_color,
-            custom_size: Some(Vec2::new(half_w * 2.0 + wall * 2.0, wall)),
-            ..default()
-        },
-        Transform::from_xyz(0.0, -half_h - wall / 2.0, 0.5),
-    ));
-    commands.spawn((
-        PlayingEntity,
-        Sprite {
-            color: wall_color,
-            custom_size: Some(Vec2::new(wall, half_h * 2.0)),
-            ..default()
-        },
-        Transform::from_xyz(-half_w - wall / 2.0, 0.0, 0.5),
-    ));
-    commands.spawn((
-        PlayingEntity,
+        tag,
+        Memory,
+        Collider::circle(MEMORY_RADIUS, Layers::PICKUP, Layers::PLAYER),
         Sprite {
-            color: wall_color,
-            custom_size: Some(Vec2::new(wall, half_h * 2.0)),
+            color: Color::srgb(0.35, 0.9, 0.95),
+            custom_size: Some(Vec2::splat(MEMORY_SIZE)),
             ..default()
         },
-        Transform::from_xyz(half_w + wall / 2.0, 0.0, 0.5),
+        Transform::from_xyz(pos.x, pos.y, 1.0),
     ));
+}
 
-    // Maze walls
-    spawn_maze_walls(&mut commands, half_w, half_h);
+/* ----------------------- ENTER COUNTDOWN ----------------------- */
+
+fn enter_countdown(
+    mut commands: Commands,
+    mut score: ResMut<Score>,
+    mut mood: ResMut<Mood>,
+    mut energy: ResMut<MoodEnergy>,
+    mut alerted: ResMut<LowTimeAlerted>,
+    current_level: Res<CurrentLevel>,
+    levels: Res<Assets<LevelData>>,
+    q_play: Query<Entity, With<PlayingEntity>>,
+    q_over: Query<Entity, With<GameOverEntity>>,
+) {
+    // Safety cleanup
+    for e in &q_over {
+        commands.entity(e).despawn();
+    }
+    for e in &q_play {
+        commands.entity(e).despawn();
+    }
+
+    // Reset run data
+    score.0 = 0;
+    *mood = Mood::default();
+    *energy = MoodEnergy::default();
+    alerted.0 = false;
+
+    // Build world
+    let level = levels.get(&current_level.handle);
+    if let Some(level) = level {
+        spawn_level(&mut commands, level, (PlayingEntity, LevelEntity));
+    }
+    let start = level.map(|l| l.player_start).unwrap_or_default();
 
     // Player
     commands.spawn((
         PlayingEntity,
         Player,
+        Velocity::default(),
+        player_collider(),
+        // Tinted by `tint_player`
         Sprite {
-            color: Color::srgb(0.95, 0.2, 0.6),
-            custom_size: Some(Vec2::new(44.0, 44.0)),
+            color: Color::WHITE,
+            custom_size: Some(Vec2::splat(PLAYER_SIZE)),
             ..default()
         },
-        Transform::from_xyz(-420.0, 0.0, 1.0),
+        Transform::from_xyz(start.x, start.y, 1.0),
     ));
 
     // HUD top bar
@@ -312,16 +422,64 @@ fn enter_countdown(
             BackgroundColor(Color::srgb(0.08, 0.08, 0.09)),
         ))
         .with_children(|ui| {
+            // Mood + the energy it takes to switch
             ui.spawn((
                 PlayingEntity,
-                HudMood,
-                Text::new("Mood: Normal (1/2/3)"),
-                TextFont {
-                    font_size: 26.0,
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(14.0),
                     ..default()
                 },
-                TextColor(Color::WHITE),
-            ));
+            ))
+            .with_children(|left| {
+                left.spawn((
+                    PlayingEntity,
+                    HudMood,
+                    Text::new("Mood:"),
+                    TextFont {
+                        font_size: 26.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+                left.spawn((
+                    PlayingEntity,
+                    Node {
+                        width: Val::Px(140.0),
+                        height: Val::Px(12.0),
+                        border: UiRect::all(Val::Px(1.0)),
+                        border_radius: BorderRadius::all(Val::Px(6.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.14, 0.14, 0.16)),
+                    BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
+                ))
+                .with_child((
+                    PlayingEntity,
+                    HudEnergyFill,
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        border_radius: BorderRadius::all(Val::Px(6.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.20, 0.90, 0.95)),
+                ));
+            });
+
+            // Generated mazes show their seed so a good one can be shared
+            if let Some(seed) = current_level.seed {
+                ui.spawn((
+                    PlayingEntity,
+                    Text::new(format!("Seed: {seed}")),
+                    TextFont {
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.6, 0.6, 0.66)),
+                ));
+            }
 
             ui.spawn((
                 PlayingEntity,
@@ -403,11 +561,27 @@ fn tick_countdown(
     timer: Option<ResMut<CountdownTimer>>,
     mut next_state: ResMut<NextState<GameState>>,
     mut q_text: Query<&mut Text, With<CountdownText>>,
+    mut sfx: MessageWriter<SoundEffect>,
 ) {
     let Some(mut timer) = timer else { return };
+    let started = timer.0.elapsed_secs() > 0.0;
+    let before = (4.0 - timer.0.elapsed_secs()).ceil().max(0.0) as i32;
     timer.0.tick(time.delta());
 
     let remaining = (4.0 - timer.0.elapsed_secs()).ceil().max(0.0) as i32;
+
+    // One tick per number (including the first "3"), a different sound for GO
+    if !started || remaining != before {
+        match remaining {
+            2..=4 => {
+                sfx.write(SoundEffect::CountdownTick);
+            }
+            1 => {
+                sfx.write(SoundEffect::CountdownGo);
+            }
+            _ => {}
+        }
+    }
     let msg = match remaining {
         4 => "3",
         3 => "2",
@@ -425,11 +599,8 @@ fn tick_countdown(
     }
 }
 
-fn countdown_input_skip(
-    keys: Res<ButtonInput<KeyCode>>,
-    mut next_state: ResMut<NextState<GameState>>,
-) {
-    if keys.just_pressed(KeyCode::Space) || keys.just_pressed(KeyCode::Enter) {
+fn countdown_input_skip(actions: Res<Actions>, mut next_state: ResMut<NextState<GameState>>) {
+    if actions.skip || actions.confirm {
         next_state.set(GameState::Playing);
     }
 }
@@ -439,10 +610,14 @@ fn countdown_input_skip(
 fn enter_playing(
     mut commands: Commands,
     config: Res<GameConfig>,
-    bounds_q: Query<&Transform, With<WorldBounds>>,
+    current_level: Res<CurrentLevel>,
+    levels: Res<Assets<LevelData>>,
     q_mem: Query<Entity, With<Memory>>,
     mut q_cd: Query<&mut Text, With<CountdownText>>,
+    mut stats: ResMut<RunStats>,
 ) {
+    *stats = RunStats::default();
+
     // Clear countdown text
     for mut t in &mut q_cd {
         *t = Text::new("");
@@ -453,164 +628,177 @@ fn enter_playing(
         commands.entity(e).despawn();
     }
 
-    // Difficulty affects time + number of memories
-    let time_limit = match config.difficulty {
-        Difficulty::Easy => 60.0,
-        Difficulty::Normal => 45.0,
-        Difficulty::Hard => 30.0,
+    let Some(level) = levels.get(&current_level.handle) else {
+        return;
     };
+
+    // Difficulty affects time + number of memories
+    let time_limit = level.time_limit.get(config.difficulty);
     commands.insert_resource(GameTimer(Timer::from_seconds(time_limit, TimerMode::Once)));
 
-    let Ok(bounds) = bounds_q.single() else {
+    spawn_memories(&mut commands, level, config.difficulty);
+}
+
+/// Dev builds watch the level files. A change during the countdown rebuilds the
+/// arena in place; during play the run restarts from the countdown (same seed), so
+/// score, stats, timer and memories all start over on the new layout.
+#[cfg(feature = "dev")]
+fn hot_reload_level(
+    mut commands: Commands,
+    mut events: MessageReader<AssetEvent<LevelData>>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    current_level: Res<CurrentLevel>,
+    levels: Res<Assets<LevelData>>,
+    q_level: Query<Entity, With<LevelEntity>>,
+) {
+    let current = current_level.handle.id();
+    let modified = events
+        .read()
+        .any(|e| matches!(e, AssetEvent::Modified { id } if *id == current));
+    if !modified {
+        return;
+    }
+    let Some(level) = levels.get(current) else {
         return;
     };
-    let half_w = bounds.translation.x;
-    let half_h = bounds.translation.y;
-
-    let mut points = vec![
-        Vec2::new(-half_w + 120.0, half_h - 90.0),
-        Vec2::new(-80.0, half_h - 60.0),
-        Vec2::new(half_w - 140.0, half_h - 140.0),
-        Vec2::new(half_w - 160.0, -half_h + 120.0),
-        Vec2::new(-half_w + 170.0, -half_h + 110.0),
-        Vec2::new(40.0, -half_h + 90.0),
-        Vec2::new(0.0, 0.0),
-    ];
+    info!("Level '{}' changed on disk, rebuilding", level.name);
 
-    match config.difficulty {
-        Difficulty::Easy => points.truncate(5),
-        Difficulty::Normal => {}
-        Difficulty::Hard => {
-            points.extend([
-                Vec2::new(-half_w + 260.0, 40.0),
-                Vec2::new(half_w - 240.0, -20.0),
-                Vec2::new(0.0, half_h - 170.0),
-                Vec2::new(0.0, -half_h + 170.0),
-            ]);
-        }
+    if *state.get() == GameState::Playing {
+        next_state.set(GameState::Countdown);
+        return;
     }
 
-    for p in points {
-        commands.spawn((
-            PlayingEntity,
-            Memory,
-            Sprite {
-                color: Color::srgb(0.35, 0.9, 0.95),
-                custom_size: Some(Vec2::new(22.0, 22.0)),
-                ..default()
-            },
-            Transform::from_xyz(p.x, p.y, 1.0),
-        ));
+    for e in &q_level {
+        commands.entity(e).despawn();
     }
+    spawn_level(&mut commands, level, (PlayingEntity, LevelEntity));
 }
 
 /* ----------------------- PLAYING UPDATE ----------------------- */
 
-fn mood_input(keys: Res<ButtonInput<KeyCode>>, mut mood: ResMut<Mood>) {
-    if keys.just_pressed(KeyCode::Digit1) {
-        *mood = Mood::Normal;
-    } else if keys.just_pressed(KeyCode::Digit2) {
-        *mood = Mood::Heavy;
-    } else if keys.just_pressed(KeyCode::Digit3) {
-        *mood = Mood::Sideways;
+/// Switch moods on request, if the energy and the cooldown allow it
+pub(crate) fn mood_input(
+    actions: Res<Actions>,
+    moods: Moods,
+    mut mood: ResMut<Mood>,
+    mut energy: ResMut<MoodEnergy>,
+    mut stats: ResMut<RunStats>,
+) {
+    let count = moods.all().len();
+    let next = match actions.mood {
+        Some(MoodIntent::Select(m)) => m,
+        Some(MoodIntent::Next) => mood.next(count),
+        Some(MoodIntent::Prev) => mood.prev(count),
+        None => return,
+    };
+    // A key bound to a slot the mood file doesn't fill
+    if next.0 >= count || next == *mood {
+        return;
+    }
+
+    let cost = moods.get(next).map_or(0.0, |m| m.switch_cost);
+    if energy.cooldown > 0.0 || energy.current < cost {
+        energy.denied = DENIED_FLASH;
+        return;
+    }
+    energy.current -= cost;
+    energy.cooldown = SWITCH_COOLDOWN;
+    *mood = next;
+    stats.mood_switches += 1;
+}
+
+/// The current mood refills or drains the meter. Running dry in a draining mood
+/// burns out: the player drops back into the first mood.
+fn mood_energy(
+    time: Res<Time>,
+    moods: Moods,
+    mut mood: ResMut<Mood>,
+    mut energy: ResMut<MoodEnergy>,
+) {
+    let dt = time.delta_secs();
+    energy.cooldown = (energy.cooldown - dt).max(0.0);
+    energy.denied = (energy.denied - dt).max(0.0);
+
+    let Some(def) = moods.get(*mood) else {
+        return;
+    };
+    energy.current = (energy.current + def.energy_rate * dt).clamp(0.0, MAX_ENERGY);
+    if energy.current <= 0.0 && def.energy_rate < 0.0 {
+        *mood = Mood::default();
+        energy.denied = DENIED_FLASH;
     }
 }
 
+/// Velocity from input, drag and gravity of the current mood (the move itself is in `collide_with_maze`)
 fn move_player(
     time: Res<Time>,
     actions: Res<Actions>,
     mood: Res<Mood>,
-    mut player_q: Query<&mut Transform, With<Player>>,
+    moods: Moods,
+    mut player_q: Query<&mut Velocity, With<Player>>,
 ) {
-    let Ok(mut t) = player_q.single_mut() else {
+    let Ok(mut velocity) = player_q.single_mut() else {
         return;
     };
-
-    let dt = time.delta_secs();
-    let base_speed = 280.0;
-    let input = actions.player_movement.unwrap_or(Vec2::ZERO);
-
-    let (speed_mul, gravity): (f32, Vec2) = match *mood {
-        Mood::Normal => (1.0, Vec2::new(0.0, -60.0)),
-        Mood::Heavy => (0.75, Vec2::new(0.0, -140.0)),
-        Mood::Sideways => (0.9, Vec2::new(-120.0, 0.0)),
-    };
-
-    let input = match *mood {
-        Mood::Sideways => Vec2::new(input.y, -input.x),
-        _ => input,
+    let Some(def) = moods.get(*mood) else {
+        return;
     };
 
-    let move_step = input * (base_speed * speed_mul) * dt;
-    let gravity_step = gravity * dt;
+    let dt = time.delta_secs();
+    let t = time.elapsed_secs();
+    let input = def.steer(actions.player_movement.unwrap_or(Vec2::ZERO), t);
 
-    t.translation += Vec3::new(
-        move_step.x + gravity_step.x,
-        move_step.y + gravity_step.y,
-        0.0,
-    );
+    let mut v = velocity.0 + (input * def.acceleration + def.pull(t)) * dt;
+    v *= (-def.drag * dt).exp();
+    velocity.0 = v.clamp_length_max(def.max_speed);
 }
 
-/// Collision vs maze walls (safe borrows)
-fn collide_with_maze(
-    mut set: ParamSet<(
-        Query<(Entity, &mut Transform, &Sprite), (With<Player>, Without<MazeWall>)>,
-        Query<(&Transform, &Sprite), (With<MazeWall>, Without<Player>)>,
-    )>,
-) {
-    // Read player info (copy out) then release borrow
-    let (player_e, start_pos, player_half) = {
-        let mut player_q = set.p0();
-        let Ok((e, pt, ps)) = player_q.single_mut() else {
-            return;
-        };
-        let size = ps.custom_size.unwrap_or(Vec2::new(44.0, 44.0));
-        (e, pt.translation.truncate(), size * 0.5)
+fn tint_player(mood: Res<Mood>, moods: Moods, mut player_q: Query<&mut Sprite, With<Player>>) {
+    let Some(def) = moods.get(*mood) else {
+        return;
     };
-
-    // Resolve locally
-    let mut pos = start_pos;
-    {
-        let walls_q = set.p1();
-        for (wt, ws) in walls_q.iter() {
-            let Some(wall_size) = ws.custom_size else {
-                continue;
-            };
-
-            let wall_half = wall_size * 0.5;
-            let wc = wt.translation.truncate();
-
-            let dx = wc.x - pos.x;
-            let px = (player_half.x + wall_half.x) - dx.abs();
-            if px <= 0.0 {
-                continue;
-            }
-
-            let dy = wc.y - pos.y;
-            let py = (player_half.y + wall_half.y) - dy.abs();
-            if py <= 0.0 {
-                continue;
-            }
-
-            // push along smaller penetration axis
-            if px < py {
-                pos.x += if dx > 0.0 { -px } else { px };
-            } else {
-                pos.y += if dy > 0.0 { -py } else { py };
-            }
+    for mut sprite in &mut player_q {
+        if sprite.color != def.color() {
+            sprite.color = def.color();
         }
     }
+}
 
-    let delta = pos - start_pos;
-    if delta == Vec2::ZERO {
+/// Move the player by its velocity, swept against the maze walls
+fn collide_with_maze(
+    time: Res<Time>,
+    mut player_q: Query<(&mut Transform, &mut Velocity, &Collider), With<Player>>,
+    solids_q: Query<(&Transform, &Collider), Without<Player>>,
+    mut touching: Local<bool>,
+    mut stats: ResMut<RunStats>,
+    mut sfx: MessageWriter<SoundEffect>,
+) {
+    let Ok((mut pt, mut velocity, collider)) = player_q.single_mut() else {
         return;
-    }
-
-    // Apply delta (new borrow)
-    let mut player_q = set.p0();
-    if let Ok((_e, mut pt, _ps)) = player_q.get_mut(player_e) {
-        pt.translation.x += delta.x;
-        pt.translation.y += delta.y;
+    };
+    let half = collider.shape.half_extents();
+
+    let walls: Vec<Rect> = solids_q
+        .iter()
+        .filter(|(_, c)| c.layers.intersects(Layers::WALL) && collider.interacts_with(c))
+        .map(|(t, c)| Rect::from_center_half_size(t.translation.truncate(), c.shape.half_extents()))
+        .collect();
+
+    // Only matters when a wall appeared on top of the player (level hot reload)
+    let (start, pushed) = collision::depenetrate(pt.translation.truncate(), half, &walls);
+    let sweep = collision::sweep(start, half, velocity.0 * time.delta_secs(), &walls);
+    let normal = sweep.normal + pushed;
+
+    pt.translation.x = sweep.pos.x;
+    pt.translation.y = sweep.pos.y;
+    velocity.0 = stop_against(velocity.0, normal);
+
+    // Gravity keeps the player pressed into walls, so only the first contact bumps
+    let was_touching = std::mem::replace(&mut *touching, normal != Vec2::ZERO);
+    if *touching && !was_touching {
+        stats.wall_collisions += 1;
+        sfx.write(SoundEffect::WallBump);
     }
 }
 
@@ -618,7 +806,7 @@ fn collide_with_maze(
 fn clamp_player(
     mut set: ParamSet<(
         Query<&Transform, (With<WorldBounds>, Without<Player>)>,
-        Query<&mut Transform, (With<Player>, Without<WorldBounds>)>,
+        Query<(&mut Transform, &mut Velocity, &Collider), (With<Player>, Without<WorldBounds>)>,
     )>,
 ) {
     let (half_w, half_h) = {
@@ -629,35 +817,68 @@ fn clamp_player(
         (bounds.translation.x, bounds.translation.y)
     };
 
-    let margin = 28.0;
-
     let mut player_q = set.p1();
-    let Ok(mut p) = player_q.single_mut() else {
+    let Ok((mut p, mut velocity, collider)) = player_q.single_mut() else {
         return;
     };
-
-    p.translation.x = p.translation.x.clamp(-half_w + margin, half_w - margin);
-    p.translation.y = p.translation.y.clamp(-half_h + margin, half_h - margin);
+    // Keep the whole collider inside the arena
+    let margin = collider.shape.half_extents();
+
+    let before = p.translation.truncate();
+    p.translation.x = p.translation.x.clamp(-half_w + margin.x, half_w - margin.x);
+    p.translation.y = p.translation.y.clamp(-half_h + margin.y, half_h - margin.y);
+    // The border pushes back like a wall
+    let pushed = p.translation.truncate() - before;
+    velocity.0 = stop_against(velocity.0, pushed);
 }
 
 fn collect_memories(
     mut commands: Commands,
-    player_q: Query<&Transform, With<Player>>,
-    memories_q: Query<(Entity, &Transform), With<Memory>>,
+    mut overlaps: MessageReader<Overlap>,
+    player_q: Query<(), With<Player>>,
+    memories_q: Query<(), With<Memory>>,
+    timer: Option<Res<GameTimer>>,
     mut score: ResMut<Score>,
+    mut stats: ResMut<RunStats>,
+    mut sfx: MessageWriter<SoundEffect>,
 ) {
-    let Ok(p) = player_q.single() else { return };
-    let pr = 26.0;
-
-    for (e, t) in &memories_q {
-        let d = p.translation.truncate().distance(t.translation.truncate());
-        if d <= pr {
+    let mut collected = Vec::new();
+    for overlap in overlaps.read() {
+        let e = overlap.sensor;
+        if overlap.layers.intersects(Layers::PICKUP)
+            && player_q.contains(overlap.entity)
+            && memories_q.contains(e)
+            && !collected.contains(&e)
+        {
+            collected.push(e);
             score.0 += 1;
+            stats
+                .pickups
+                .push(timer.as_ref().map_or(0.0, |t| t.0.elapsed_secs()));
+            sfx.write(SoundEffect::MemoryCollected);
             commands.entity(e).despawn();
         }
     }
 }
 
+/// Distance and time per mood, once the player's position is final for the frame
+fn track_run_stats(
+    time: Res<Time>,
+    mood: Res<Mood>,
+    player_q: Query<&Transform, With<Player>>,
+    mut stats: ResMut<RunStats>,
+) {
+    let Ok(p) = player_q.single() else { return };
+    let pos = p.translation.truncate();
+    if let Some(last) = stats.last_pos.replace(pos) {
+        stats.distance += last.distance(pos);
+    }
+    if stats.mood_time.len() <= mood.0 {
+        stats.mood_time.resize(mood.0 + 1, 0.0);
+    }
+    stats.mood_time[mood.0] += time.delta_secs();
+}
+
 fn tick_game_timer(time: Res<Time>, timer: Option<ResMut<GameTimer>>) {
     let Some(mut timer) = timer else { return };
     timer.0.tick(time.delta());
@@ -665,25 +886,60 @@ fn tick_game_timer(time: Res<Time>, timer: Option<ResMut<GameTimer>>) {
 
 /* ----------------------- HUD UPDATE ----------------------- */
 
+/// Keys of the mood slots the mood file fills, e.g. `1/2/3`
+pub(crate) fn mood_keys_hint(bindings: &InputBindings, mood_count: usize) -> String {
+    (0..MOOD_SLOTS)
+        .take(mood_count)
+        .map(|slot| bindings.primary_label(GameControl::Mood(slot)))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Fill of the energy bar: dimmed while cooling down, red when a switch was refused
+fn show_energy(
+    energy: &MoodEnergy,
+    q_fill: &mut Query<(&mut Node, &mut BackgroundColor), With<HudEnergyFill>>,
+) {
+    let color = if energy.denied > 0.0 {
+        Color::srgb(1.0, 0.3, 0.3)
+    } else if energy.cooldown > 0.0 {
+        Color::srgb(0.12, 0.50, 0.55)
+    } else {
+        Color::srgb(0.20, 0.90, 0.95)
+    };
+    for (mut node, mut bg) in q_fill.iter_mut() {
+        node.width = Val::Percent(energy.current / MAX_ENERGY * 100.0);
+        bg.0 = color;
+    }
+}
+
+/// `Mood: Heavy (1/2/3)`, in the mood's colour
+fn hud_mood_line(mood: Mood, moods: &Moods, bindings: &InputBindings) -> (String, Color) {
+    let keys = mood_keys_hint(bindings, moods.all().len());
+    let color = moods.get(mood).map_or(Color::WHITE, |m| m.color());
+    (format!("Mood: {} ({keys})", moods.name(mood)), color)
+}
+
 fn update_hud_countdown(
     mood: Res<Mood>,
+    moods: Moods,
+    energy: Res<MoodEnergy>,
+    mut q_energy: Query<(&mut Node, &mut BackgroundColor), With<HudEnergyFill>>,
+    bindings: Res<InputBindings>,
     score: Res<Score>,
     mut set: ParamSet<(
-        Query<&mut Text, With<HudMood>>,
+        Query<(&mut Text, &mut TextColor), With<HudMood>>,
         Query<&mut Text, With<HudScore>>,
         Query<(&mut Text, &mut TextColor), With<HudTime>>,
         Query<&mut Text, With<HurryText>>,
     )>,
 ) {
-    let mood_label = match *mood {
-        Mood::Normal => "Normal",
-        Mood::Heavy => "Heavy",
-        Mood::Sideways => "Sideways",
-    };
-
-    for mut t in set.p0().iter_mut() {
-        *t = Text::new(format!("Mood: {mood_label} (1/2/3)"));
+    let (mood_line, mood_color) = hud_mood_line(*mood, &moods, &bindings);
+    for (mut t, mut color) in set.p0().iter_mut() {
+        *t = Text::new(mood_line.clone());
+        color.0 = mood_color;
     }
+    show_energy(&energy, &mut q_energy);
     for mut t in set.p1().iter_mut() {
         *t = Text::new(format!("Score: {}", score.0));
     }
@@ -698,25 +954,27 @@ fn update_hud_countdown(
 
 fn update_hud_playing(
     mood: Res<Mood>,
+    moods: Moods,
+    energy: Res<MoodEnergy>,
+    mut q_energy: Query<(&mut Node, &mut BackgroundColor), With<HudEnergyFill>>,
+    bindings: Res<InputBindings>,
     score: Res<Score>,
     timer: Option<Res<GameTimer>>,
     mut alerted: ResMut<LowTimeAlerted>,
     mut set: ParamSet<(
-        Query<&mut Text, With<HudMood>>,
+        Query<(&mut Text, &mut TextColor), With<HudMood>>,
         Query<&mut Text, With<HudScore>>,
         Query<(&mut Text, &mut TextColor), With<HudTime>>,
         Query<&mut Text, With<HurryText>>,
     )>,
+    mut sfx: MessageWriter<SoundEffect>,
 ) {
-    let mood_label = match *mood {
-        Mood::Normal => "Normal",
-        Mood::Heavy => "Heavy",
-        Mood::Sideways => "Sideways",
-    };
-
-    for mut t in set.p0().iter_mut() {
-        *t = Text::new(format!("Mood: {mood_label} (1/2/3)"));
+    let (mood_line, mood_color) = hud_mood_line(*mood, &moods, &bindings);
+    for (mut t, mut color) in set.p0().iter_mut() {
+        *t = Text::new(mood_line.clone());
+        color.0 = mood_color;
     }
+    show_energy(&energy, &mut q_energy);
 
     for mut t in set.p1().iter_mut() {
         *t = Text::new(format!("Score: {}", score.0));
@@ -732,6 +990,9 @@ fn update_hud_playing(
         *t = Text::new(format!("Time: {:.1}", remaining));
         if remaining <= 7.0 {
             color.0 = Color::srgb(1.0, 0.3, 0.3);
+            if !alerted.0 {
+                sfx.write(SoundEffect::HurryUp);
+            }
             alerted.0 = true;
         } else {
             color.0 = Color::WHITE;
@@ -744,26 +1005,81 @@ fn update_hud_playing(
 }
 
 fn check_game_over(
+    mut commands: Commands,
     timer: Option<Res<GameTimer>>,
     memories_q: Query<Entity, With<Memory>>,
+    mut score: ResMut<Score>,
+    stats: Res<RunStats>,
+    mut ended: MessageWriter<RunEnded>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
     let Some(timer) = timer else { return };
 
-    if timer.0.just_finished() || memories_q.is_empty() {
-        next_state.set(GameState::GameOver);
-    }
+    // Picking up the last memory wins even on the frame the timer runs out
+    let missed = memories_q.iter().count() as u32;
+    let result = if missed == 0 {
+        RunResult::Victory
+    } else if timer.0.just_finished() {
+        RunResult::TimeUp
+    } else {
+        return;
+    };
+
+    let remaining = timer.0.remaining_secs();
+    let time_bonus = match result {
+        RunResult::Victory => remaining.floor() as u32 * TIME_BONUS_PER_SECOND,
+        RunResult::TimeUp => 0,
+    };
+    let outcome = RunOutcome {
+        result,
+        time: timer.0.elapsed_secs(),
+        remaining,
+        collected: score.0,
+        missed,
+        time_bonus,
+        score: score.0 + time_bonus,
+    };
+    score.0 = outcome.score;
+    // Balancing data ends up in the log as well
+    info!("Run ended: {outcome:?}, {stats:?}");
+
+    commands.insert_resource(outcome);
+    ended.write(RunEnded(outcome));
+    next_state.set(GameState::GameOver);
 }
 
 /* ----------------------- GAME OVER ----------------------- */
 
-fn setup_game_over(mut commands: Commands, score: Res<Score>, config: Res<GameConfig>) {
+fn setup_game_over(
+    mut commands: Commands,
+    score: Res<Score>,
+    config: Res<GameConfig>,
+    bindings: Res<InputBindings>,
+    current_level: Res<CurrentLevel>,
+    outcome: Res<RunOutcome>,
+    rank: Option<Res<RunRank>>,
+    stats: Res<RunStats>,
+    moods: Moods,
+    level_assets: Res<LevelAssets>,
+) {
     let name = if config.player_name.trim().is_empty() {
         "Player"
     } else {
         config.player_name.trim()
     };
 
+    // Cleared a campaign level -> Next Level; out of time -> Retry
+    let next = next_level(&config, *outcome, level_assets.campaign.len());
+    let replay_label = match outcome.result {
+        RunResult::TimeUp => "Retry",
+        RunResult::Victory => "Play Again",
+    };
+    let confirm_label = if next.is_some() {
+        "Next Level"
+    } else {
+        replay_label
+    };
+
     commands
         .spawn((
             GameOverEntity,
@@ -775,6 +1091,7 @@ fn setup_game_over(mut commands: Commands, score: Res<Score>, config: Res<GameCo
                 height: Val::Percent(100.0),
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
+                column_gap: Val::Px(20.0),
                 ..default()
             },
             BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
@@ -794,16 +1111,50 @@ fn setup_game_over(mut commands: Commands, score: Res<Score>, config: Res<GameCo
                 BackgroundColor(Color::srgb(0.10, 0.10, 0.12)),
             ))
             .with_children(|card| {
+                let (title, title_color) = match outcome.result {
+                    RunResult::Victory => (
+                        format!("Dream cleared, {name}!"),
+                        Color::srgb(0.35, 0.92, 0.55),
+                    ),
+                    RunResult::TimeUp => (
+                        format!("Time's up, {name}."),
+                        Color::srgb(0.95, 0.35, 0.45),
+                    ),
+                };
                 card.spawn((
                     GameOverEntity,
-                    Text::new(format!("Nice run, {name}.")),
+                    Text::new(title),
                     TextFont {
                         font_size: 44.0,
                         ..default()
                     },
-                    TextColor(Color::WHITE),
+                    TextColor(title_color),
                 ));
 
+                let total = outcome.collected + outcome.missed;
+                let mut stats = vec![format!(
+                    "Memories: {}/{total} (missed {})",
+                    outcome.collected, outcome.missed
+                )];
+                if outcome.is_victory() {
+                    stats.insert(0, format!("Clear time: {:.1}s", outcome.time));
+                    stats.push(format!(
+                        "Time left: {:.1}s  →  +{} bonus",
+                        outcome.remaining, outcome.time_bonus
+                    ));
+                }
+                for line in stats {
+                    card.spawn((
+                        GameOverEntity,
+                        Text::new(line),
+                        TextFont {
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                    ));
+                }
+
                 card.spawn((
                     GameOverEntity,
                     Text::new(format!("Final Score: {}", score.0)),
@@ -814,6 +1165,50 @@ fn setup_game_over(mut commands: Commands, score: Res<Score>, config: Res<GameCo
                     TextColor(Color::WHITE),
                 ));
 
+                if let Some(rank) = &rank {
+                    let (line, color) = if rank.rank == 0 {
+                        (
+                            format!(
+                                "New best! Rank #1 on {} ({})",
+                                rank.board,
+                                config.difficulty.label()
+                            ),
+                            Color::srgb(0.98, 0.82, 0.30),
+                        )
+                    } else {
+                        (
+                            format!(
+                                "Rank #{} on {} ({})",
+                                rank.rank + 1,
+                                rank.board,
+                                config.difficulty.label()
+                            ),
+                            Color::srgb(0.35, 0.9, 0.95),
+                        )
+                    };
+                    card.spawn((
+                        GameOverEntity,
+                        Text::new(line),
+                        TextFont {
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(color),
+                    ));
+                }
+
+                if let Some(seed) = current_level.seed {
+                    card.spawn((
+                        GameOverEntity,
+                        Text::new(format!("Maze seed: {seed}")),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.6, 0.6, 0.66)),
+                    ));
+                }
+
                 card.spawn((
                     GameOverEntity,
                     Text::new("Play again? Pick a difficulty:"),
@@ -872,13 +1267,39 @@ fn setup_game_over(mut commands: Commands, score: Res<Score>, config: Res<GameCo
                     },
                 ))
                 .with_children(|row| {
+                    if let Some(next) = next {
+                        row.spawn((
+                            GameOverEntity,
+                            Button,
+                            NextLevelButton(next),
+                            BackgroundColor(Color::srgb(0.18, 0.18, 0.20)),
+                            Node {
+                                width: Val::Px(150.0),
+                                height: Val::Px(56.0),
+                                align_items: AlignItems::Center,
+                                justify_content: JustifyContent::Center,
+                                border_radius: BorderRadius::all(Val::Px(12.0)),
+                                ..default()
+                            },
+                        ))
+                        .with_child((
+                            GameOverEntity,
+                            Text::new("Next Level"),
+                            TextFont {
+                                font_size: 22.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                    }
+
                     row.spawn((
                         GameOverEntity,
                         Button,
                         ReplayButton,
                         BackgroundColor(Color::srgb(0.18, 0.18, 0.20)),
                         Node {
-                            width: Val::Px(180.0),
+                            width: Val::Px(150.0),
                             height: Val::Px(56.0),
                             align_items: AlignItems::Center,
                             justify_content: JustifyContent::Center,
@@ -888,7 +1309,7 @@ fn setup_game_over(mut commands: Commands, score: Res<Score>, config: Res<GameCo
                     ))
                     .with_child((
                         GameOverEntity,
-                        Text::new("Play Again"),
+                        Text::new(replay_label),
                         TextFont {
                             font_size: 22.0,
                             ..default()
@@ -902,7 +1323,7 @@ fn setup_game_over(mut commands: Commands, score: Res<Score>, config: Res<GameCo
                         BackToMenuButton,
                         BackgroundColor(Color::srgb(0.18, 0.18, 0.20)),
                         Node {
-                            width: Val::Px(180.0),
+                            width: Val::Px(120.0),
                             height: Val::Px(56.0),
                             align_items: AlignItems::Center,
                             justify_content: JustifyContent::Center,
@@ -926,7 +1347,7 @@ fn setup_game_over(mut commands: Commands, score: Res<Score>, config: Res<GameCo
                         QuitButton,
                         BackgroundColor(Color::srgb(0.18, 0.18, 0.20)),
                         Node {
-                            width: Val::Px(140.0),
+                            width: Val::Px(100.0),
                             height: Val::Px(56.0),
                             align_items: AlignItems::Center,
                             justify_content: JustifyContent::Center,
@@ -947,7 +1368,14 @@ fn setup_game_over(mut commands: Commands, score: Res<Score>, config: Res<GameCo
 
                 card.spawn((
                     GameOverEntity,
-                    Text::new("Keys: Enter/R = Play Again    M = Menu    Esc/Q = Quit"),
+                    Text::new(format!(
+                        "Keys: {} = {confirm_label}    {} = {replay_label}    {} = Menu    {}/{} = Quit",
+                        bindings.primary_label(GameControl::Confirm),
+                        bindings.primary_label(GameControl::Replay),
+                        bindings.primary_label(GameControl::BackToMenu),
+                        bindings.primary_label(GameControl::Cancel),
+                        bindings.primary_label(GameControl::Quit),
+                    )),
                     TextFont {
                         font_size: 16.0,
                         ..default()
@@ -955,23 +1383,115 @@ fn setup_game_over(mut commands: Commands, score: Res<Score>, config: Res<GameCo
                     TextColor(Color::srgb(0.75, 0.75, 0.78)),
                 ));
             });
+
+            spawn_run_breakdown(ui, &stats, &moods, outcome.time);
         });
 }
 
+/// Side panel: where the time went and the split time of every memory
+fn spawn_run_breakdown(
+    ui: &mut ChildSpawnerCommands,
+    stats: &RunStats,
+    moods: &Moods,
+    run_time: f32,
+) {
+    let share = |t: f32| {
+        if run_time > 0.0 {
+            t / run_time * 100.0
+        } else {
+            0.0
+        }
+    };
+
+    let mut lines = vec![
+        format!("Distance: {:.0}", stats.distance),
+        format!("Mood switches: {}", stats.mood_switches),
+        format!("Wall hits: {}", stats.wall_collisions),
+    ];
+    if stats.fever_spikes > 0 {
+        lines.push(format!("Fever spikes: {}", stats.fever_spikes));
+    }
+    for (i, def) in moods.all().iter().enumerate() {
+        let t = stats.time_in(Mood(i));
+        lines.push(format!("{}: {t:.1}s ({:.0}%)", def.name, share(t)));
+    }
+
+    let mut splits = Vec::with_capacity(stats.pickups.len());
+    let mut previous = 0.0;
+    for (i, &at) in stats.pickups.iter().enumerate() {
+        splits.push(format!("#{}  {at:.1}s  (+{:.1}s)", i + 1, at - previous));
+        previous = at;
+    }
+    if splits.is_empty() {
+        splits.push("No memories collected".to_string());
+    }
+
+    ui.spawn((
+        GameOverEntity,
+        Node {
+            width: Val::Px(260.0),
+            padding: UiRect::all(Val::Px(20.0)),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(6.0),
+            border_radius: BorderRadius::all(Val::Px(16.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.10, 0.10, 0.12)),
+    ))
+    .with_children(|panel| {
+        for (title, lines) in [("RUN BREAKDOWN", lines), ("SPLITS", splits)] {
+            panel.spawn((
+                GameOverEntity,
+                Text::new(title),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.35, 0.9, 0.95)),
+                Node {
+                    margin: UiRect::top(Val::Px(6.0)),
+                    ..default()
+                },
+            ));
+            for line in lines {
+                panel.spawn((
+                    GameOverEntity,
+                    Text::new(line),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                ));
+            }
+        }
+    });
+}
+
 fn game_over_input_keys(
-    keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<Actions>,
+    outcome: Res<RunOutcome>,
+    level_assets: Res<LevelAssets>,
+    mut config: ResMut<GameConfig>,
     mut next_state: ResMut<NextState<GameState>>,
     mut exit: MessageWriter<AppExit>,
 ) {
-    if keys.just_pressed(KeyCode::Enter) || keys.just_pressed(KeyCode::KeyR) {
+    if actions.confirm {
+        // Moving on is a new run; staying on the level is a retry and keeps the seed
+        if let Some(next) = next_level(&config, *outcome, level_assets.campaign.len()) {
+            config.level = next;
+            reroll_seed(&mut config);
+        }
+        next_state.set(GameState::Countdown);
+    } else if actions.replay {
         next_state.set(GameState::Countdown);
     }
 
-    if keys.just_pressed(KeyCode::KeyM) {
+    if actions.back_to_menu {
         next_state.set(GameState::Menu);
     }
 
-    if keys.just_pressed(KeyCode::KeyQ) || keys.just_pressed(KeyCode::Escape) {
+    if actions.quit || actions.cancel {
         exit.write(AppExit::Success);
     }
 }
@@ -981,6 +1501,7 @@ fn game_over_buttons(
         (
             &Interaction,
             Option<&ReplayButton>,
+            Option<&NextLevelButton>,
             Option<&QuitButton>,
             Option<&BackToMenuButton>,
             Option<&DifficultyChoiceButton>,
@@ -991,7 +1512,7 @@ fn game_over_buttons(
     mut next_state: ResMut<NextState<GameState>>,
     mut exit: MessageWriter<AppExit>,
 ) {
-    for (i, replay, quit, menu, diff) in &mut q {
+    for (i, replay, next, quit, menu, diff) in &mut q {
         if *i != Interaction::Pressed {
             continue;
         }
@@ -1001,7 +1522,11 @@ fn game_over_buttons(
             continue;
         }
 
-        if replay.is_some() {
+        if let Some(next) = next {
+            config.level = next.0;
+            reroll_seed(&mut config);
+            next_state.set(GameState::Countdown);
+        } else if replay.is_some() {
             next_state.set(GameState::Countdown);
         } else if menu.is_some() {
             next_state.set(GameState::Menu);
@@ -1062,3 +1587,193 @@ fn cleanup_game_over(mut commands: Commands, q: Query<Entity, With<GameOverEntit
         commands.entity(e).despawn();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::loading::MoodAssets;
+    use crate::mood::{MoodSet, tests::fever_moods};
+
+    const HALF: f32 = PLAYER_HALF_EXTENTS.x;
+
+    /// Player at `start` moving at `velocity`, plus one maze wall per rect
+    fn world_with(start: Vec2, velocity: Vec2, walls: &[Rect]) -> World {
+        let mut world = World::new();
+        world.init_resource::<Actions>();
+        world.init_resource::<Mood>();
+        world.init_resource::<RunStats>();
+        world.init_resource::<Assets<MoodSet>>();
+        let set = world.resource_mut::<Assets<MoodSet>>().add(fever_moods());
+        world.insert_resource(MoodAssets { set });
+        world.init_resource::<Messages<SoundEffect>>();
+        world.insert_resource(Time::<()>::default());
+
+        world.spawn((
+            Player,
+            Velocity(velocity),
+            player_collider(),
+            Transform::from_translation(start.extend(1.0)),
+        ));
+        for wall in walls {
+            world.spawn((
+                MazeWall,
+                Collider::aabb(wall.size(), Layers::WALL, Layers::PLAYER),
+                Transform::from_translation(wall.center().extend(0.0)),
+            ));
+        }
+        world
+    }
+
+    /// One physics step of `dt` seconds, as `FixedUpdate` runs it
+    fn step(world: &mut World, dt: f32) {
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(dt));
+        world.run_system_once(move_player).unwrap();
+        world.run_system_once(collide_with_maze).unwrap();
+    }
+
+    /// Only the collision move, so the velocity stays what the test set
+    fn slide(world: &mut World, dt: f32) {
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(dt));
+        world.run_system_once(collide_with_maze).unwrap();
+    }
+
+    fn player(world: &mut World) -> (Vec2, Vec2) {
+        let (t, v) = world
+            .query_filtered::<(&Transform, &Velocity), With<Player>>()
+            .single(world)
+            .unwrap();
+        (t.translation.truncate(), v.0)
+    }
+
+    fn overlaps_any(pos: Vec2, walls: &[Rect]) -> bool {
+        let player = Rect::from_center_half_size(pos, Vec2::splat(HALF));
+        walls.iter().any(|w| !w.intersect(player).is_empty())
+    }
+
+    #[test]
+    fn long_steps_do_not_tunnel_through_thin_walls() {
+        // A 20px maze wall to the right of the player
+        let wall = Rect::from_center_size(Vec2::new(100.0, 0.0), Vec2::new(20.0, 400.0));
+        for dt in [0.05, 0.25, 1.0, 5.0] {
+            for velocity in [Vec2::new(2000.0, 0.0), Vec2::new(1500.0, 300.0)] {
+                let mut world = world_with(Vec2::ZERO, velocity, &[wall]);
+                slide(&mut world, dt);
+
+                let (pos, after) = player(&mut world);
+                assert!(
+                    pos.x + HALF <= wall.min.x,
+                    "dt {dt}, {velocity}: ended at {pos}"
+                );
+                assert!(!overlaps_any(pos, &[wall]), "dt {dt}: inside the wall");
+                assert_eq!(after.x, 0.0, "dt {dt}: still moving into the wall");
+            }
+        }
+    }
+
+    #[test]
+    fn heavy_gravity_does_not_sink_through_the_floor() {
+        let floor = Rect::from_center_size(Vec2::new(0.0, -60.0), Vec2::new(600.0, 20.0));
+        let mut world = world_with(Vec2::ZERO, Vec2::ZERO, &[floor]);
+        let heavy = fever_moods().moods.iter().position(|m| m.name == "Heavy");
+        world.insert_resource(Mood(heavy.unwrap()));
+        for _ in 0..20 {
+            step(&mut world, 2.0);
+        }
+
+        let (pos, _) = player(&mut world);
+        assert!(pos.y - HALF >= floor.max.y, "ended at {pos}");
+    }
+
+    #[test]
+    fn corner_stops_both_axes_without_jitter() {
+        // Floor + wall on the right, the player pushed diagonally into the corner
+        let walls = [
+            Rect::from_center_size(Vec2::new(0.0, -60.0), Vec2::new(400.0, 20.0)),
+            Rect::from_center_size(Vec2::new(80.0, 100.0), Vec2::new(20.0, 340.0)),
+        ];
+        let mut world = world_with(Vec2::new(-50.0, 50.0), Vec2::ZERO, &walls);
+        world.resource_mut::<Actions>().player_movement = Some(Vec2::new(1.0, -1.0));
+        for _ in 0..40 {
+            step(&mut world, 0.1);
+        }
+        let (settled, _) = player(&mut world);
+
+        for dt in [0.25, 1.0, 0.01, 3.0, 0.5] {
+            step(&mut world, dt);
+            let (pos, _) = player(&mut world);
+            assert!(!overlaps_any(pos, &walls), "inside a wall at {pos}");
+            assert!(pos.distance(settled) < 0.1, "moved from {settled} to {pos}");
+        }
+        assert_eq!(
+            settled.round(),
+            Vec2::new(walls[1].min.x - HALF, walls[0].max.y + HALF)
+        );
+    }
+
+    /// Ask for `to` like a mood key would, returns the mood afterwards
+    fn request_mood(world: &mut World, to: usize) -> Mood {
+        world.resource_mut::<Actions>().mood = Some(MoodIntent::Select(Mood(to)));
+        world.run_system_once(mood_input).unwrap();
+        *world.resource::<Mood>()
+    }
+
+    #[test]
+    fn switching_moods_costs_energy() {
+        let mut world = world_with(Vec2::ZERO, Vec2::ZERO, &[]);
+        world.init_resource::<MoodEnergy>();
+        let cost = fever_moods().moods[1].switch_cost;
+
+        assert_eq!(request_mood(&mut world, 1), Mood(1));
+        assert_eq!(world.resource::<MoodEnergy>().current, MAX_ENERGY - cost);
+        // Straight back is still on cooldown
+        assert_eq!(request_mood(&mut world, 0), Mood(1));
+
+        // Cooled down, but the meter is empty
+        *world.resource_mut::<MoodEnergy>() = MoodEnergy {
+            current: 1.0,
+            ..default()
+        };
+        assert_eq!(request_mood(&mut world, 0), Mood(1));
+        assert!(world.resource::<MoodEnergy>().denied > 0.0);
+        assert_eq!(world.resource::<RunStats>().mood_switches, 1);
+    }
+
+    #[cfg(feature = "dev")]
+    #[test]
+    fn level_edit_during_play_restarts_the_run() {
+        let mut world = World::new();
+        world.init_resource::<Assets<LevelData>>();
+        world.init_resource::<Messages<AssetEvent<LevelData>>>();
+        let level: LevelData =
+            ron::de::from_str(include_str!("../assets/levels/dream.level.ron")).unwrap();
+        let handle = world.resource_mut::<Assets<LevelData>>().add(level);
+        let id = handle.id();
+        world.insert_resource(CurrentLevel { handle, seed: None });
+        world.insert_resource(State::new(GameState::Playing));
+        world.init_resource::<NextState<GameState>>();
+        world.spawn((LevelEntity, Transform::default()));
+
+        world.write_message(AssetEvent::<LevelData>::Modified { id });
+        world.run_system_once(hot_reload_level).unwrap();
+
+        assert!(matches!(
+            world.resource::<NextState<GameState>>(),
+            NextState::Pending(GameState::Countdown)
+        ));
+        // The countdown rebuilds everything; nothing is patched in place mid-run
+        let levels = world
+            .query_filtered::<(), With<LevelEntity>>()
+            .iter(&world)
+            .count();
+        assert_eq!(levels, 1);
+    }
+}
@@ -0,0 +1,438 @@
+use bevy::prelude::*;
+
+use crate::actions::Actions;
+use crate::audio::{AudioSettings, SoundEffect, save_audio_settings};
+use crate::{GameState, PauseState};
+
+/// Audio settings screen reached from the main menu or the pause overlay
+pub struct SettingsMenuPlugin;
+
+#[derive(Component)]
+struct SettingsTag;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum VolumeSlider {
+    Master,
+    Music,
+    Sfx,
+}
+
+impl VolumeSlider {
+    const ALL: [VolumeSlider; 3] = [VolumeSlider::Master, VolumeSlider::Music, VolumeSlider::Sfx];
+
+    fn label(self) -> &'static str {
+        match self {
+            VolumeSlider::Master => "Master",
+            VolumeSlider::Music => "Music",
+            VolumeSlider::Sfx => "Sound FX",
+        }
+    }
+
+    fn get(self, settings: &AudioSettings) -> f32 {
+        match self {
+            VolumeSlider::Master => settings.master,
+            VolumeSlider::Music => settings.music,
+            VolumeSlider::Sfx => settings.sfx,
+        }
+    }
+
+    fn get_mut(self, settings: &mut AudioSettings) -> &mut f32 {
+        match self {
+            VolumeSlider::Master => &mut settings.master,
+            VolumeSlider::Music => &mut settings.music,
+            VolumeSlider::Sfx => &mut settings.sfx,
+        }
+    }
+}
+
+/// Step applied by the - / + buttons
+const VOLUME_STEP: f32 = 0.1;
+const BAR_WIDTH: f32 = 220.0;
+
+#[derive(Component)]
+struct VolumeButton {
+    slider: VolumeSlider,
+    step: f32,
+}
+
+#[derive(Component)]
+struct VolumeFill(VolumeSlider);
+
+#[derive(Component)]
+struct VolumeText(VolumeSlider);
+
+#[derive(Component)]
+struct MuteButton;
+
+#[derive(Component)]
+struct MuteText;
+
+#[derive(Component)]
+struct BackButton;
+
+impl Plugin for SettingsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Settings), setup_settings_menu)
+            .add_systems(OnEnter(PauseState::Settings), setup_settings_menu)
+            .add_systems(
+                Update,
+                (
+                    settings_back_input,
+                    settings_buttons,
+                    refresh_settings_texts,
+                    settings_visuals,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Settings).or(in_state(PauseState::Settings))),
+            )
+            .add_systems(OnExit(GameState::Settings), cleanup_settings_menu)
+            .add_systems(OnExit(PauseState::Settings), cleanup_settings_menu);
+    }
+}
+
+fn setup_settings_menu(mut commands: Commands) {
+    commands
+        .spawn((
+            SettingsTag,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.05, 0.05, 0.06)),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                SettingsTag,
+                Node {
+                    width: Val::Px(620.0),
+                    padding: UiRect::all(Val::Px(24.0)),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(14.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    border_radius: BorderRadius::all(Val::Px(18.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.09, 0.09, 0.11)),
+                BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
+            ))
+            .with_children(|card| {
+                card.spawn((
+                    SettingsTag,
+                    Text::new("SETTINGS"),
+                    TextFont {
+                        font_size: 40.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.95, 0.95, 0.98)),
+                ));
+
+                card.spawn((
+                    SettingsTag,
+                    Text::new("AUDIO"),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.76, 0.76, 0.80)),
+                ));
+
+                for slider in VolumeSlider::ALL {
+                    spawn_volume_row(card, slider);
+                }
+
+                card.spawn((
+                    SettingsTag,
+                    Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(12.0),
+                        margin: UiRect::top(Val::Px(10.0)),
+                        ..default()
+                    },
+                ))
+                .with_children(|row| {
+                    row.spawn((
+                        SettingsTag,
+                        Button,
+                        BackButton,
+                        Node {
+                            width: Val::Px(200.0),
+                            height: Val::Px(52.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(1.0)),
+                            border_radius: BorderRadius::all(Val::Px(14.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.12, 0.18, 0.20)),
+                        BorderColor::all(Color::srgb(0.20, 0.60, 0.65)),
+                    ))
+                    .with_child((
+                        SettingsTag,
+                        Text::new("BACK"),
+                        TextFont {
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+
+                    row.spawn((
+                        SettingsTag,
+                        Button,
+                        MuteButton,
+                        Node {
+                            width: Val::Px(200.0),
+                            height: Val::Px(52.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(1.0)),
+                            border_radius: BorderRadius::all(Val::Px(14.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.14, 0.14, 0.16)),
+                        BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
+                    ))
+                    .with_child((
+                        SettingsTag,
+                        MuteText,
+                        Text::new(""),
+                        TextFont {
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            });
+        });
+}
+
+fn spawn_volume_row(card: &mut ChildSpawnerCommands, slider: VolumeSlider) {
+    card.spawn((
+        SettingsTag,
+        Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(12.0),
+            ..default()
+        },
+    ))
+    .with_children(|row| {
+        row.spawn((
+            SettingsTag,
+            Text::new(slider.label()),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.86, 0.86, 0.90)),
+            Node {
+                width: Val::Px(110.0),
+                ..default()
+            },
+        ));
+
+        spawn_step_button(row, slider, -VOLUME_STEP, "-");
+
+        // Bar
+        row.spawn((
+            SettingsTag,
+            Node {
+                width: Val::Px(BAR_WIDTH),
+                height: Val::Px(14.0),
+                border_radius: BorderRadius::all(Val::Px(7.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.16, 0.16, 0.20)),
+        ))
+        .with_child((
+            SettingsTag,
+            VolumeFill(slider),
+            Node {
+                width: Val::Percent(0.0),
+                height: Val::Percent(100.0),
+                border_radius: BorderRadius::all(Val::Px(7.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.20, 0.90, 0.95)),
+        ));
+
+        spawn_step_button(row, slider, VOLUME_STEP, "+");
+
+        row.spawn((
+            SettingsTag,
+            VolumeText(slider),
+            Text::new(""),
+            TextFont {
+                font_size: 17.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.35, 0.9, 0.95)),
+        ));
+    });
+}
+
+fn spawn_step_button(row: &mut ChildSpawnerCommands, slider: VolumeSlider, step: f32, label: &str) {
+    row.spawn((
+        SettingsTag,
+        Button,
+        VolumeButton { slider, step },
+        Node {
+            width: Val::Px(40.0),
+            height: Val::Px(34.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(1.0)),
+            border_radius: BorderRadius::all(Val::Px(10.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.14, 0.14, 0.16)),
+        BorderColor::all(Color::srgb(0.22, 0.22, 0.28)),
+    ))
+    .with_child((
+        SettingsTag,
+        Text::new(label),
+        TextFont {
+            font_size: 22.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+    ));
+}
+
+/// Back to wherever the screen was opened from
+fn leave_settings(
+    pause: &Option<Res<State<PauseState>>>,
+    next_state: &mut NextState<GameState>,
+    next_pause: &mut NextState<PauseState>,
+) {
+    if pause.is_some() {
+        next_pause.set(PauseState::Paused);
+    } else {
+        next_state.set(GameState::Menu);
+    }
+}
+
+fn settings_back_input(
+    actions: Res<Actions>,
+    pause: Option<Res<State<PauseState>>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut next_pause: ResMut<NextState<PauseState>>,
+) {
+    if actions.cancel {
+        leave_settings(&pause, &mut next_state, &mut next_pause);
+    }
+}
+
+fn settings_buttons(
+    q: Query<
+        (
+            &Interaction,
+            Option<&VolumeButton>,
+            Option<&MuteButton>,
+            Option<&BackButton>,
+        ),
+        (With<Button>, Changed<Interaction>),
+    >,
+    mut settings: ResMut<AudioSettings>,
+    mut sfx: MessageWriter<SoundEffect>,
+    pause: Option<Res<State<PauseState>>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut next_pause: ResMut<NextState<PauseState>>,
+) {
+    for (i, volume, mute, back) in &q {
+        if *i != Interaction::Pressed {
+            continue;
+        }
+
+        if let Some(v) = volume {
+            let level = v.slider.get_mut(&mut settings);
+            // Snap to whole steps so repeated clicks land on 0% / 100% exactly
+            *level = ((*level + v.step) / VOLUME_STEP).round() * VOLUME_STEP;
+            *level = level.clamp(0.0, 1.0);
+            save_audio_settings(&settings);
+            // Let the player hear the new level
+            sfx.write(SoundEffect::CountdownTick);
+        } else if mute.is_some() {
+            settings.muted = !settings.muted;
+            save_audio_settings(&settings);
+        } else if back.is_some() {
+            leave_settings(&pause, &mut next_state, &mut next_pause);
+        }
+    }
+}
+
+fn refresh_settings_texts(
+    settings: Res<AudioSettings>,
+    mut q_fill: Query<(&VolumeFill, &mut Node)>,
+    mut q_text: Query<(&VolumeText, &mut Text), Without<MuteText>>,
+    mut q_mute: Query<&mut Text, With<MuteText>>,
+    q_spawned: Query<(), Added<MuteText>>,
+) {
+    if !settings.is_changed() && q_spawned.is_empty() {
+        return;
+    }
+
+    for (fill, mut node) in &mut q_fill {
+        node.width = Val::Percent(fill.0.get(&settings) * 100.0);
+    }
+    for (t, mut text) in &mut q_text {
+        let percent = (t.0.get(&settings) * 100.0).round();
+        *text = Text::new(format!("{percent}%"));
+    }
+    for mut text in &mut q_mute {
+        *text = Text::new(if settings.muted {
+            "SOUND: OFF"
+        } else {
+            "SOUND: ON"
+        });
+    }
+}
+
+fn settings_visuals(
+    settings: Res<AudioSettings>,
+    mut q: Query<
+        (
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            Option<&MuteButton>,
+        ),
+        (With<Button>, Without<BackButton>, With<SettingsTag>),
+    >,
+) {
+    let dim_border = Color::srgb(0.22, 0.22, 0.28);
+    let cyan = Color::srgb(0.20, 0.90, 0.95);
+    let purple = Color::srgb(0.85, 0.25, 0.95);
+    let red = Color::srgb(0.95, 0.35, 0.45);
+
+    for (i, mut bg, mut border, mute) in &mut q {
+        match *i {
+            Interaction::Pressed => {
+                bg.0 = Color::srgb(0.24, 0.24, 0.30);
+                *border = BorderColor::all(purple);
+            }
+            Interaction::Hovered => {
+                bg.0 = Color::srgb(0.20, 0.20, 0.24);
+                *border = BorderColor::all(cyan);
+            }
+            Interaction::None if mute.is_some() && settings.muted => {
+                bg.0 = Color::srgb(0.16, 0.12, 0.14);
+                *border = BorderColor::all(red);
+            }
+            Interaction::None => {
+                bg.0 = Color::srgb(0.14, 0.14, 0.16);
+                *border = BorderColor::all(dim_border);
+            }
+        }
+    }
+}
+
+fn cleanup_settings_menu(mut commands: Commands, q: Query<Entity, With<SettingsTag>>) {
+    for e in &q {
+        commands.entity(e).despawn();
+    }
+}
@@ -0,0 +1,97 @@
+//! Tiny key/value persistence for settings and save data.
+//!
+//! Native builds write `<config dir>/fever_dream/<key>.ron`,
+//! the web build keeps the same text in `localStorage`.
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// Read and parse a stored value. Missing or broken data yields `None`.
+pub fn load<T: DeserializeOwned>(key: &str) -> Option<T> {
+    let text = read_raw(key)?;
+    match ron::from_str(&text) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            bevy::log::warn!("Ignoring unreadable save data '{key}': {e}");
+            None
+        }
+    }
+}
+
+/// Serialize and store a value, logging (not panicking) on failure.
+pub fn save<T: Serialize>(key: &str, value: &T) {
+    let text = match ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()) {
+        Ok(t) => t,
+        Err(e) => {
+            bevy::log::warn!("Could not serialize save data '{key}': {e}");
+            return;
+        }
+    };
+
+    if let Err(e) = write_raw(key, &text) {
+        bevy::log::warn!("Could not write save data '{key}': {e}");
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn path_for(key: &str) -> std::path::PathBuf {
+    config_dir().join("fever_dream").join(format!("{key}.ron"))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn config_dir() -> std::path::PathBuf {
+    use std::env::var_os;
+    use std::path::PathBuf;
+
+    if cfg!(target_os = "windows") {
+        if let Some(dir) = var_os("APPDATA") {
+            return PathBuf::from(dir);
+        }
+    } else if cfg!(target_os = "macos") {
+        if let Some(home) = var_os("HOME") {
+            return PathBuf::from(home).join("Library/Application Support");
+        }
+    } else if let Some(dir) = var_os("XDG_CONFIG_HOME") {
+        return PathBuf::from(dir);
+    } else if let Some(home) = var_os("HOME") {
+        return PathBuf::from(home).join(".config");
+    }
+
+    // Last resort: next to wherever we were started from
+    PathBuf::from(".")
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_raw(key: &str) -> Option<String> {
+    std::fs::read_to_string(path_for(key)).ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write_raw(key: &str, text: &str) -> Result<(), String> {
+    let path = path_for(key);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    std::fs::write(path, text).map_err(|e| e.to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read_raw(key: &str) -> Option<String> {
+    local_storage()?
+        .get_item(&format!("fever_dream.{key}"))
+        .ok()
+        .flatten()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn write_raw(key: &str, text: &str) -> Result<(), String> {
+    let storage = local_storage().ok_or("localStorage unavailable")?;
+    storage
+        .set_item(&format!("fever_dream.{key}"), text)
+        .map_err(|e| format!("{e:?}"))
+}
@@ -0,0 +1,153 @@
+//! Editable single-line text with a caret, used by the menu's name box.
+//!
+//! Positions and the length limit are counted in `char`s, so accented and
+//! non-Latin names behave the same as ASCII ones.
+
+#[derive(Debug, Clone)]
+pub struct TextField {
+    text: String,
+    /// Caret position in chars (0 = before the first char)
+    caret: usize,
+    max_chars: usize,
+}
+
+impl TextField {
+    pub fn new(initial: &str, max_chars: usize) -> Self {
+        let text: String = initial.chars().take(max_chars).collect();
+        let caret = text.chars().count();
+        Self {
+            text,
+            caret,
+            max_chars,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn len_chars(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    /// Text before and after the caret
+    pub fn split_at_caret(&self) -> (&str, &str) {
+        self.text.split_at(self.byte_index(self.caret))
+    }
+
+    /// Insert at the caret, dropping control chars and anything past the limit.
+    /// Returns true if the text changed.
+    pub fn insert_str(&mut self, input: &str) -> bool {
+        let room = self.max_chars.saturating_sub(self.len_chars());
+        let accepted: String = input
+            .chars()
+            .filter(|c| !c.is_control())
+            .take(room)
+            .collect();
+        if accepted.is_empty() {
+            return false;
+        }
+
+        let at = self.byte_index(self.caret);
+        self.text.insert_str(at, &accepted);
+        self.caret += accepted.chars().count();
+        true
+    }
+
+    pub fn backspace(&mut self) -> bool {
+        if self.caret == 0 {
+            return false;
+        }
+        self.caret -= 1;
+        let at = self.byte_index(self.caret);
+        self.text.remove(at);
+        true
+    }
+
+    pub fn delete(&mut self) -> bool {
+        if self.caret >= self.len_chars() {
+            return false;
+        }
+        let at = self.byte_index(self.caret);
+        self.text.remove(at);
+        true
+    }
+
+    pub fn move_left(&mut self) -> bool {
+        self.move_to(self.caret.saturating_sub(1))
+    }
+
+    pub fn move_right(&mut self) -> bool {
+        self.move_to(self.caret + 1)
+    }
+
+    pub fn move_home(&mut self) -> bool {
+        self.move_to(0)
+    }
+
+    pub fn move_end(&mut self) -> bool {
+        self.move_to(self.len_chars())
+    }
+
+    fn move_to(&mut self, caret: usize) -> bool {
+        let caret = caret.min(self.len_chars());
+        let moved = caret != self.caret;
+        self.caret = caret;
+        moved
+    }
+
+    fn byte_index(&self, char_idx: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(char_idx)
+            .map(|(i, _)| i)
+            .unwrap_or(self.text.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn caret_moves_by_chars() {
+        let mut field = TextField::new("zoë", 12);
+        assert!(field.move_left());
+        assert_eq!(field.split_at_caret(), ("zo", "ë"));
+        assert!(field.insert_str("é"));
+        assert_eq!(field.split_at_caret(), ("zoé", "ë"));
+        assert!(field.move_home());
+        assert!(!field.move_left());
+        assert!(field.move_end());
+        assert!(!field.move_right());
+        assert_eq!(field.text(), "zoéë");
+    }
+
+    #[test]
+    fn backspace_and_delete_stop_at_the_edges() {
+        let mut field = TextField::new("åß", 12);
+        assert!(!field.delete());
+        assert!(field.backspace());
+        assert_eq!(field.text(), "å");
+
+        field.move_home();
+        assert!(!field.backspace());
+        assert!(field.delete());
+        assert_eq!(field.text(), "");
+        assert!(!field.delete());
+    }
+
+    #[test]
+    fn limit_counts_chars_not_bytes() {
+        let mut field = TextField::new("日本語テキスト", 4);
+        assert_eq!(field.text(), "日本語テ");
+        assert!(!field.insert_str("x"));
+
+        let mut field = TextField::new("ab", 4);
+        field.move_home();
+        // Only the first two fit, control chars never count
+        assert!(field.insert_str("\té🙂ü"));
+        assert_eq!(field.text(), "é🙂ab");
+        assert_eq!(field.len_chars(), 4);
+    }
+}